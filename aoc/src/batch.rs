@@ -0,0 +1,294 @@
+// Batch mode: runs a day on every input in a directory and checks each against the answers next
+// to it, to see that a solution works for everyone's input and not just for ours.
+//
+// Inputs are the `*.txt` files in the directory. The answers for `alice.txt` are in
+// `alice.answer`, one line per part:
+//
+//     2165
+//     534035653563227
+//
+// A missing file or an empty line means the answer to that part is not known.
+
+use crate::bench::format_ns;
+use crate::days::Day;
+use crate::ledger::{Ledger, Status};
+use common::solution::Part;
+use common::Answer;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The outcome of one part on one input.
+#[derive(Debug, Clone)]
+pub struct Checked {
+    pub part: Part,
+    pub answer: Answer,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+/// The parts run on one input.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<Checked>,
+}
+
+/// The outcome of one input: the parts, or why they could not be run.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub input: String,
+    pub result: Result<Run, String>,
+}
+
+/// How one input fared as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every part ran, and the ones with a known answer gave it.
+    Passed,
+    /// The input could not be run, or a part gave a different answer than expected.
+    Failed,
+    /// Every part ran, but no answer was known to check them against.
+    Unchecked,
+}
+
+impl Entry {
+    /// Whether the input passed, failed or could not be checked.
+    pub fn outcome(&self) -> Outcome {
+        let run = match &self.result {
+            Ok(run) => run,
+            Err(_) => return Outcome::Failed,
+        };
+        let statuses = || run.parts.iter().map(|checked| &checked.status);
+        if statuses().any(|status| matches!(status, Status::Wrong(_))) {
+            Outcome::Failed
+        } else if statuses().any(|status| *status == Status::Ok) {
+            Outcome::Passed
+        } else {
+            Outcome::Unchecked
+        }
+    }
+}
+
+/// The answers file belonging to the input at `input`.
+pub fn answer_path(input: &Path) -> PathBuf {
+    input.with_extension("answer")
+}
+
+/// Reads answers in the format of an answers file, one line per part.
+pub fn parse_answers(text: &str) -> Ledger {
+    let mut ledger = Ledger::default();
+    for (&part, line) in [Part::One, Part::Two].iter().zip(text.lines()) {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let answer = match line.parse::<i64>() {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::from(line),
+        };
+        ledger.record(part, &answer);
+    }
+
+    ledger
+}
+
+fn load_answers(path: &Path) -> Result<Ledger, String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(parse_answers(&text)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Ledger::default()),
+        Err(e) => Err(format!("Could not read answers {}: {}", path.display(), e)),
+    }
+}
+
+/// The `*.txt` files in `directory`, sorted by name.
+pub fn inputs(directory: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = std::fs::read_dir(directory)
+        .map_err(|e| format!("Could not read directory {}: {}", directory.display(), e))?;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("Could not read directory {}: {}", directory.display(), e))?
+            .path();
+        if path.is_file() && path.extension().is_some_and(|extension| extension == "txt") {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+
+    Ok(inputs)
+}
+
+fn check(day: &Day, parts: &[Part], path: &Path, name: &str) -> Result<Run, String> {
+    let input = common::input::read(path).map_err(|e| format!("Could not read input: {}", e))?;
+    let answers = load_answers(&answer_path(path))?;
+    let timed = (day.run)(&input, parts).map_err(|e| e.with_file(name).to_string())?;
+
+    let parts = parts
+        .iter()
+        .zip(timed.answers)
+        .map(|(&part, (answer, duration))| Checked {
+            part,
+            status: answers.check(part, &answer),
+            answer,
+            duration,
+        })
+        .collect();
+    Ok(Run {
+        parse: timed.parse,
+        parts,
+    })
+}
+
+/// Runs `day` on every input in `directory`. An input that cannot be read or parsed fails, but
+/// does not stop the others.
+pub fn run(day: &Day, parts: &[Part], directory: &Path) -> Result<Vec<Entry>, String> {
+    let inputs = inputs(directory)?;
+    if inputs.is_empty() {
+        return Err(format!("No *.txt inputs in {}", directory.display()));
+    }
+
+    Ok(inputs
+        .iter()
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            Entry {
+                input: name.to_string(),
+                result: check(day, parts, path, &name),
+            }
+        })
+        .collect())
+}
+
+fn result_name(status: &Status) -> &'static str {
+    match status {
+        Status::Ok => "PASS",
+        Status::Wrong(_) => "FAIL",
+        Status::Unknown => "-",
+    }
+}
+
+/// Prints one line per input and part, and how many inputs passed, failed or went unchecked.
+pub fn print(entries: &[Entry]) {
+    println!(
+        "{:<20} {:<9} {:>20} {:>20} {:>12} Result",
+        "Input", "Step", "Answer", "Expected", "Time"
+    );
+    for entry in entries {
+        let run = match &entry.result {
+            Ok(run) => run,
+            Err(e) => {
+                println!(
+                    "{:<20} {:<9} {:>20} {:>20} {:>12} FAIL",
+                    entry.input, "Parse", "", "", ""
+                );
+                println!("    {}", e);
+                continue;
+            }
+        };
+        println!(
+            "{:<20} {:<9} {:>20} {:>20} {:>12}",
+            entry.input,
+            "Parse",
+            "",
+            "",
+            format_ns(run.parse.as_nanos() as u64)
+        );
+        for checked in &run.parts {
+            let expected = match &checked.status {
+                Status::Ok => checked.answer.to_string(),
+                Status::Wrong(expected) => expected.to_string(),
+                Status::Unknown => "?".to_owned(),
+            };
+            println!(
+                "{:<20} {:<9} {:>20} {:>20} {:>12} {}",
+                entry.input,
+                checked.part.to_string(),
+                checked.answer.to_string(),
+                expected,
+                format_ns(checked.duration.as_nanos() as u64),
+                result_name(&checked.status)
+            );
+        }
+    }
+
+    let count = |outcome| {
+        entries
+            .iter()
+            .filter(|entry| entry.outcome() == outcome)
+            .count()
+    };
+    println!(
+        "{} passed, {} failed, {} unchecked",
+        count(Outcome::Passed),
+        count(Outcome::Failed),
+        count(Outcome::Unchecked)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_file() {
+        let answers = parse_answers("2165\n534035653563227\n");
+        assert_eq!(answers.get(Part::One), Some(Answer::Number(2165)));
+        assert_eq!(
+            answers.get(Part::Two),
+            Some(Answer::Number(534035653563227))
+        );
+
+        let answers = parse_answers("\nshinygold\n");
+        assert_eq!(answers.get(Part::One), None);
+        assert_eq!(answers.get(Part::Two), Some(Answer::from("shinygold")));
+
+        assert_eq!(
+            answer_path(Path::new("inputs/alice.txt")),
+            Path::new("inputs/alice.answer")
+        );
+    }
+
+    #[test]
+    fn batch_of_inputs() {
+        let directory = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let files = [
+            ("a.txt", "939\n7,13,x,x,59,x,31,19\n"),
+            ("a.answer", "295\n1068781\n"),
+            ("b.txt", "939\n7,13,x,x,59,x,31,19\n"),
+            ("b.answer", "295\n1\n"),
+            ("c.txt", "939\n"),
+            ("d.txt", "939\n17,x,13,19\n"),
+            ("notes.md", "not an input"),
+        ];
+        for (name, text) in files.iter() {
+            std::fs::write(directory.join(name), text).unwrap();
+        }
+
+        let day = crate::days::find(13).unwrap();
+        let entries = run(day, &[Part::One, Part::Two], &directory).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        let names: Vec<_> = entries.iter().map(|entry| entry.input.as_str()).collect();
+        assert_eq!(names, ["a.txt", "b.txt", "c.txt", "d.txt"]);
+        let outcomes: Vec<_> = entries.iter().map(Entry::outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                Outcome::Passed,
+                Outcome::Failed,
+                Outcome::Failed,
+                Outcome::Unchecked
+            ]
+        );
+        let statuses: Vec<_> = entries[3]
+            .result
+            .as_ref()
+            .unwrap()
+            .parts
+            .iter()
+            .map(|checked| checked.status.clone())
+            .collect();
+        assert_eq!(statuses, [Status::Unknown, Status::Unknown]);
+    }
+}
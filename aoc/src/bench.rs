@@ -0,0 +1,149 @@
+// Benchmark mode: runs a day a number of times and reports how long parsing and each part took.
+
+use crate::days::Day;
+use common::solution::Part;
+use common::ParseResult;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Fastest, median and slowest time of a single step, in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let middle = nanos.len() / 2;
+        let median_ns = if nanos.len().is_multiple_of(2) {
+            (nanos[middle - 1] + nanos[middle]) / 2
+        } else {
+            nanos[middle]
+        };
+
+        Stats {
+            min_ns: nanos[0],
+            median_ns,
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayReport {
+    pub day: u32,
+    pub title: &'static str,
+    pub input: String,
+    pub parse: Stats,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Stats>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+/// Runs `day` on `input` `iterations` times. Every iteration parses the input anew.
+pub fn bench_day(
+    day: &Day,
+    parts: &[Part],
+    input: &str,
+    input_name: String,
+    iterations: usize,
+) -> ParseResult<DayReport> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut samples = vec![Vec::with_capacity(iterations); parts.len()];
+
+    for _ in 0..iterations {
+        let timed = (day.run)(input, parts)?;
+        parse.push(timed.parse);
+        for (i, (_, duration)) in timed.answers.into_iter().enumerate() {
+            samples[i].push(duration);
+        }
+    }
+
+    let mut report = DayReport {
+        day: day.number,
+        title: day.title,
+        input: input_name,
+        parse: Stats::from_samples(&parse),
+        part_one: None,
+        part_two: None,
+    };
+    for (part, samples) in parts.iter().zip(samples) {
+        let stats = Some(Stats::from_samples(&samples));
+        match part {
+            Part::One => report.part_one = stats,
+            Part::Two => report.part_two = stats,
+        }
+    }
+
+    Ok(report)
+}
+
+/// Formats a duration in the largest unit that keeps it at least 1.
+pub fn format_ns(nanos: u64) -> String {
+    let duration = Duration::from_nanos(nanos);
+    if nanos >= 1_000_000_000 {
+        format!("{:.3} s", duration.as_secs_f64())
+    } else if nanos >= 1_000_000 {
+        format!("{:.3} ms", duration.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.3} µs", duration.as_secs_f64() * 1e6)
+    }
+}
+
+/// Prints the report as a table, one line per day and step.
+pub fn print(report: &Report) {
+    println!(
+        "{:<4} {:<9} {:>12} {:>12} {:>12}",
+        "Day", "Step", "Min", "Median", "Max"
+    );
+    for day in &report.days {
+        let steps = [
+            ("Parse", Some(day.parse)),
+            ("Part One", day.part_one),
+            ("Part Two", day.part_two),
+        ];
+        for (name, stats) in steps.iter() {
+            if let Some(stats) = stats {
+                println!(
+                    "{:<4} {:<9} {:>12} {:>12} {:>12}",
+                    day.day,
+                    name,
+                    format_ns(stats.min_ns),
+                    format_ns(stats.median_ns),
+                    format_ns(stats.max_ns)
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<_> = [5, 1, 3].iter().map(|&n| Duration::from_nanos(n)).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!((stats.min_ns, stats.median_ns, stats.max_ns), (1, 3, 5));
+
+        let samples: Vec<_> = [4, 1, 2, 8]
+            .iter()
+            .map(|&n| Duration::from_nanos(n))
+            .collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!((stats.min_ns, stats.median_ns, stats.max_ns), (1, 3, 8));
+    }
+}
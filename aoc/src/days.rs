@@ -0,0 +1,93 @@
+// Registry of all days the runner knows about.
+//
+// Every day is handed the full text of its puzzle input. Unless another input is given on the
+// command line, that is the `input` file in the day's directory.
+
+use common::solution::{self, Part, Timed};
+use common::{Generate, ParseResult, Rng, Solution};
+
+pub struct Day {
+    pub number: u32,
+    pub title: &'static str,
+    pub directory: &'static str,
+    pub run: fn(&str, &[Part]) -> ParseResult<Timed>,
+    /// Why a part has no answer for an input, and what comes closest.
+    pub unsolved: fn(&str, Part) -> Option<String>,
+    /// Size of the real input, for `generate`.
+    pub size: usize,
+    /// Largest size `generate` supports.
+    pub max_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+    /// Parses arbitrary bytes, panicking if the parser misbehaves.
+    #[cfg(test)]
+    pub fuzz: fn(&[u8]),
+}
+
+macro_rules! day {
+    ($solution:ty, $directory:expr) => {
+        Day {
+            number: <$solution>::DAY,
+            title: <$solution>::TITLE,
+            directory: $directory,
+            run: solution::run_timed::<$solution>,
+            unsolved: solution::unsolved::<$solution>,
+            size: <$solution as Generate>::SIZE,
+            max_size: <$solution as Generate>::MAX_SIZE,
+            generate: <$solution as Generate>::generate,
+            #[cfg(test)]
+            fuzz: common::fuzz::parse::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01, "day01"),
+    day!(day02::Day02, "day02"),
+    day!(day03::Day03, "day03"),
+    day!(day04::Day04, "day04"),
+    day!(day05::Day05, "day05"),
+    day!(day06::Day06, "day06"),
+    day!(day07::Day07, "day07"),
+    day!(day08::Day08, "day08"),
+    day!(day09::Day09, "day09"),
+    day!(day10::Day10, "day10"),
+    day!(day11::Day11, "day11"),
+    day!(day12::Day12, "day12"),
+    day!(day13::Day13, "day13"),
+    day!(day14::Day14, "day14"),
+    day!(day15::Day15, "day15"),
+    day!(day16::Day16, "day16"),
+    day!(day17::Day17, "day17"),
+    day!(day18::Day18, "day18"),
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    // Mutations tried per starting input.
+    const MUTATIONS: usize = 300;
+
+    #[test]
+    fn parsers_survive_mutations() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for day in DAYS {
+            let mut inputs = vec![(day.generate)(&mut Rng::new(0), 20).into_bytes()];
+            if let Ok(input) = fs::read(root.join(day.directory).join("input")) {
+                inputs.push(input);
+            }
+            let mut rng = Rng::new(u64::from(day.number));
+            for input in &inputs {
+                for _ in 0..MUTATIONS {
+                    (day.fuzz)(&common::fuzz::mutate(&mut rng, input));
+                }
+            }
+        }
+    }
+}
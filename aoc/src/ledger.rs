@@ -0,0 +1,162 @@
+// Answer ledger: the accepted answers for an input, kept in a TOML file next to it.
+//
+// For the input `day13/input` that is `day13/input.answers.toml`:
+//
+//     part_one = 2165
+//     part_two = 534035653563227
+//
+// After a run every answer is compared against the ledger, so a change that breaks a solution
+// shows up as WRONG instead of going unnoticed.
+
+use common::solution::Part;
+use common::Answer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ledger {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_one: Option<Recorded>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_two: Option<Recorded>,
+}
+
+/// How an answer compares to the one in the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Same as the recorded answer.
+    Ok,
+    /// Differs from the recorded answer, which is kept here.
+    Wrong(Answer),
+    /// Nothing recorded for this part yet.
+    Unknown,
+}
+
+impl Status {
+    /// The status as a single word, without the expected answer.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "OK",
+            Status::Wrong(_) => "WRONG",
+            Status::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Wrong(expected) => write!(f, "WRONG (expected {})", expected),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/// The ledger belonging to the input at `input`.
+pub fn path_for(input: &Path) -> PathBuf {
+    let mut name = input.file_name().unwrap_or_default().to_os_string();
+    name.push(".answers.toml");
+    input.with_file_name(name)
+}
+
+impl Ledger {
+    /// Reads the ledger at `path`. A missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Ledger, String> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Ledger::default()),
+            Err(e) => return Err(format!("Could not read answers {}: {}", path.display(), e)),
+        };
+        toml::from_str(&text).map_err(|e| format!("Invalid answers {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, text)
+            .map_err(|e| format!("Could not write answers {}: {}", path.display(), e))
+    }
+
+    fn slot(&mut self, part: Part) -> &mut Option<Recorded> {
+        match part {
+            Part::One => &mut self.part_one,
+            Part::Two => &mut self.part_two,
+        }
+    }
+
+    /// The accepted answer for `part`, if there is one.
+    pub fn get(&self, part: Part) -> Option<Answer> {
+        let recorded = match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        };
+        recorded.as_ref().map(|recorded| match recorded {
+            Recorded::Number(n) => Answer::Number(*n),
+            Recorded::Text(s) => Answer::Text(s.clone()),
+        })
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Status {
+        match self.get(part) {
+            Some(ref expected) if expected == answer => Status::Ok,
+            Some(expected) => Status::Wrong(expected),
+            None => Status::Unknown,
+        }
+    }
+
+    /// Accepts `answer` for `part`. Unsolved parts are not recorded. Returns whether the ledger
+    /// changed.
+    pub fn record(&mut self, part: Part, answer: &Answer) -> bool {
+        let recorded = match answer {
+            Answer::Number(n) => Recorded::Number(*n),
+            Answer::Text(s) => Recorded::Text(s.clone()),
+            Answer::Unsolved => return false,
+        };
+        let slot = self.slot(part);
+        if slot.as_ref() == Some(&recorded) {
+            return false;
+        }
+        *slot = Some(recorded);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_and_record() {
+        let mut ledger: Ledger = toml::from_str("part_one = 2165\n").unwrap();
+        assert_eq!(ledger.check(Part::One, &Answer::Number(2165)), Status::Ok);
+        assert_eq!(
+            ledger.check(Part::One, &Answer::Number(2166)),
+            Status::Wrong(Answer::Number(2165))
+        );
+        assert_eq!(ledger.check(Part::Two, &Answer::Number(1)), Status::Unknown);
+
+        assert!(!ledger.record(Part::One, &Answer::Number(2165)));
+        assert!(!ledger.record(Part::Two, &Answer::Unsolved));
+        assert!(ledger.record(Part::Two, &Answer::from("abc")));
+        assert_eq!(
+            toml::to_string(&ledger).unwrap(),
+            "part_one = 2165\npart_two = \"abc\"\n"
+        );
+    }
+
+    #[test]
+    fn ledger_path() {
+        assert_eq!(
+            path_for(Path::new("day13/input")),
+            Path::new("day13/input.answers.toml")
+        );
+    }
+}
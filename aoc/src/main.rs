@@ -0,0 +1,427 @@
+use clap::{Parser, Subcommand};
+use common::logging;
+use common::solution::{Part, PARTS};
+use common::{Answer, Rng};
+use std::path::{Path, PathBuf};
+
+mod batch;
+mod bench;
+mod days;
+mod ledger;
+mod output;
+mod scaffold;
+mod tui;
+
+use days::Day;
+use ledger::{Ledger, Status};
+use output::{Format, Record};
+
+/// Advent of Code 2020 runner.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    /// Show debug output of the days, or traces with -vv
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Log levels per day, e.g. `day11=trace,day13=debug`
+    #[arg(long, value_name = "DIRECTIVES", global = true)]
+    log: Vec<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or `all` of them
+    Run {
+        /// Day number (1-25) or `all`
+        day: String,
+        /// Only run this part (1 or 2)
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Puzzle input to use instead of the day's own `input` file, `-` reads stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Time parsing and each part instead of printing the answers
+        #[arg(long)]
+        bench: bool,
+        /// Number of runs per day when benchmarking
+        #[arg(
+            long,
+            default_value_t = 10,
+            value_parser = clap::value_parser!(u64).range(1..),
+            requires = "bench"
+        )]
+        iterations: u64,
+        /// Where to write the JSON benchmark report
+        #[arg(long, default_value = "bench.json", requires = "bench")]
+        report: PathBuf,
+        /// Record the answers in the input's answer ledger instead of only checking them
+        #[arg(long, conflicts_with = "bench")]
+        update: bool,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "bench")]
+        format: Format,
+    },
+    /// Run a day on every `*.txt` input in a directory and check the answers in the `*.answer`
+    /// file next to each
+    Batch {
+        /// Day number (1-25)
+        day: u32,
+        /// Directory with the inputs and their answers
+        directory: PathBuf,
+        /// Only run this part (1 or 2)
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+    },
+    /// Step through the simulation of day 11, 12 or 17 in the terminal
+    Tui {
+        /// Day number (11, 12 or 17)
+        day: u32,
+        /// Which part's rules to simulate (1 or 2)
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Puzzle input to use instead of the day's own `input` file, `-` reads stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Steps per second when playing
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..=tui::MAX_SPEED))]
+        speed: u64,
+    },
+    /// Create the crate for a new day and register it in the runner
+    New {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// Title of the puzzle
+        #[arg(long, default_value = "Untitled")]
+        title: String,
+    },
+    /// Generate a random puzzle input for a day
+    Gen {
+        /// Day number (1-25)
+        day: u32,
+        /// Seed of the random numbers; the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the input in lines, passports, rules, … depending on the day [default: the
+        /// size of the real input]
+        #[arg(long)]
+        size: Option<usize>,
+        /// Where to write the input instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+// The day crates live next to the runner in the workspace.
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn default_input(day: &Day) -> PathBuf {
+    workspace_root()
+        .join(day.directory)
+        .join(common::input::DEFAULT_PATH)
+}
+
+fn parts(part: Option<u32>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => PARTS.to_vec(),
+    }
+}
+
+// Name of an input in the output, the same between checkouts in different places.
+fn input_name(path: &Path) -> PathBuf {
+    let root = workspace_root();
+    common::input::describe(path.strip_prefix(&root).unwrap_or(path))
+}
+
+/// Runs `day` and checks the answers against the ledger of the input, recording them with
+/// `update`. Returns the number of answers that differ from the ledger.
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    path: &Path,
+    update: bool,
+    format: Format,
+) -> Result<usize, String> {
+    let input = common::input::read(path).map_err(|e| {
+        format!(
+            "Could not read input {} for day {}: {}",
+            path.display(),
+            day.number,
+            e
+        )
+    })?;
+
+    let name = input_name(path);
+    let timed = (day.run)(&input, parts).map_err(|e| e.with_file(&name).to_string())?;
+
+    // Input from stdin has nowhere to keep its answers
+    let ledger_path = Some(path)
+        .filter(|&path| path != Path::new("-"))
+        .map(ledger::path_for);
+    let mut ledger = match &ledger_path {
+        Some(ledger_path) => Ledger::load(ledger_path)?,
+        None => Ledger::default(),
+    };
+
+    let mut records = Vec::new();
+    let mut wrong = 0;
+    let mut changed = false;
+    for (&part, (answer, duration)) in parts.iter().zip(timed.answers) {
+        let status = ledger_path.as_ref().map(|_| ledger.check(part, &answer));
+        if update {
+            changed |= ledger.record(part, &answer);
+        } else if let Some(Status::Wrong(_)) = status {
+            wrong += 1;
+        }
+        let note = match answer {
+            Answer::Unsolved => (day.unsolved)(&input, part),
+            _ => None,
+        };
+        records.push(Record {
+            day: day.number,
+            part,
+            answer,
+            duration,
+            input: name.display().to_string(),
+            status,
+            note,
+        });
+    }
+    output::print_day(format, day.number, day.title, &records);
+
+    if let (true, Some(ledger_path)) = (changed, ledger_path) {
+        ledger.save(&ledger_path)?;
+        eprintln!("Recorded answers in {}", ledger_path.display());
+    }
+
+    Ok(wrong)
+}
+
+fn bench_day(
+    day: &Day,
+    parts: &[Part],
+    path: &Path,
+    iterations: usize,
+) -> Result<bench::DayReport, String> {
+    let input = common::input::read(path).map_err(|e| {
+        format!(
+            "Could not read input {} for day {}: {}",
+            path.display(),
+            day.number,
+            e
+        )
+    })?;
+
+    let name = input_name(path);
+    bench::bench_day(day, parts, &input, name.display().to_string(), iterations)
+        .map_err(|e| e.with_file(name).to_string())
+}
+
+fn write_report(report: &bench::Report, path: &Path) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).map_err(|e| e.to_string())?;
+    std::fs::write(path, json + "\n")
+        .map_err(|e| format!("Could not write report {}: {}", path.display(), e))
+}
+
+fn generate(
+    day: &Day,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<&Path>,
+) -> Result<(), String> {
+    let size = size.unwrap_or(day.size);
+    if size > day.max_size {
+        return Err(format!(
+            "Day {} generates inputs up to size {}, not {}",
+            day.number, day.max_size, size
+        ));
+    }
+    let input = (day.generate)(&mut Rng::new(seed), size);
+    match output {
+        Some(path) => std::fs::write(path, input)
+            .map_err(|e| format!("Could not write input {}: {}", path.display(), e)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn view(number: u32, part: Part, input: Option<PathBuf>, speed: u64) -> Result<(), String> {
+    let day = days::find(number).ok_or_else(|| format!("No solution for day {}", number))?;
+    let path = input.unwrap_or_else(|| default_input(day));
+    let text = common::input::read(&path).map_err(|e| {
+        format!(
+            "Could not read input {} for day {}: {}",
+            path.display(),
+            day.number,
+            e
+        )
+    })?;
+
+    let viewer = tui::simulation(day.number, &text, part)
+        .ok_or_else(|| format!("Day {} does not run a simulation", day.number))?
+        .map_err(|e| e.with_file(input_name(&path)).to_string())?;
+    let title = format!("Day {}: {}, {}", day.number, day.title, part);
+    tui::run(viewer, &title, speed).map_err(|e| format!("Terminal error: {}", e))
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let mut filter = logging::Filter::new(cli.verbose);
+    for directives in &cli.log {
+        if let Err(e) = filter.add_directives(directives) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+    logging::init(filter);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            bench,
+            iterations,
+            report,
+            update,
+            format,
+        } => {
+            let selected: Vec<&Day> = if day == "all" {
+                if input.is_some() {
+                    eprintln!("Error: --input can only be used when running a single day");
+                    std::process::exit(1);
+                }
+                days::DAYS.iter().collect()
+            } else {
+                match day.parse::<u32>().ok().and_then(days::find) {
+                    Some(day) => vec![day],
+                    None => {
+                        eprintln!("Error: No solution for day {}", day);
+                        std::process::exit(1);
+                    }
+                }
+            };
+
+            if bench {
+                let mut days = Vec::new();
+                for day in selected {
+                    let path = input.clone().unwrap_or_else(|| default_input(day));
+                    match bench_day(day, &parts(part), &path, iterations as usize) {
+                        Ok(day_report) => days.push(day_report),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+
+                let bench_report = bench::Report {
+                    iterations: iterations as usize,
+                    days,
+                };
+                bench::print(&bench_report);
+                if let Err(e) = write_report(&bench_report, &report) {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+                return;
+            }
+
+            output::print_start(format);
+            let mut wrong = 0;
+            for day in selected {
+                let path = input.clone().unwrap_or_else(|| default_input(day));
+                match run_day(day, &parts(part), &path, update, format) {
+                    Ok(n) => wrong += n,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            if wrong > 0 {
+                eprintln!("Error: {} answer(s) differ from the answer ledger", wrong);
+                std::process::exit(1);
+            }
+        }
+        Command::Batch {
+            day,
+            directory,
+            part,
+        } => {
+            let day = match days::find(day) {
+                Some(day) => day,
+                None => {
+                    eprintln!("Error: No solution for day {}", day);
+                    std::process::exit(1);
+                }
+            };
+            let entries = match batch::run(day, &parts(part), &directory) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            batch::print(&entries);
+            if entries
+                .iter()
+                .any(|entry| entry.outcome() == batch::Outcome::Failed)
+            {
+                std::process::exit(1);
+            }
+        }
+        Command::Tui {
+            day,
+            part,
+            input,
+            speed,
+        } => {
+            if let Err(e) = view(day, parts(Some(part))[0], input, speed) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::New { day, title } => match scaffold::new_day(&workspace_root(), day, &title) {
+            Ok(files) => {
+                for file in files {
+                    println!("{}", input_name(&file).display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let day = match days::find(day) {
+                Some(day) => day,
+                None => {
+                    eprintln!("Error: No solution for day {}", day);
+                    std::process::exit(1);
+                }
+            };
+            if let Err(e) = generate(day, seed, size, output.as_deref()) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
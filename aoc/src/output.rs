@@ -0,0 +1,178 @@
+// Output formats for the answers of a run: plain text for people, JSON and CSV for scripts.
+
+use crate::ledger::Status;
+use common::solution::Part;
+use common::Answer;
+use serde::{Serialize, Serializer};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// The answers, with a header per day
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Comma-separated values, with a header row
+    Csv,
+}
+
+/// The answer to one part of one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u32,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    #[serde(serialize_with = "answer_value")]
+    pub answer: Answer,
+    #[serde(rename = "duration_ns", serialize_with = "nanoseconds")]
+    pub duration: Duration,
+    pub input: String,
+    /// How the answer compares to the ledger. `None` if the input has no ledger.
+    #[serde(serialize_with = "status_name")]
+    pub status: Option<Status>,
+    /// Why the part has no answer, and what comes closest, if the day can tell.
+    pub note: Option<String>,
+}
+
+fn part_number<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u32(number(*part))
+}
+
+fn answer_value<S: Serializer>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::Number(n) => serializer.serialize_i64(*n),
+        Answer::Text(s) => serializer.serialize_str(s),
+        Answer::Unsolved => serializer.serialize_none(),
+    }
+}
+
+fn nanoseconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+fn status_name<S: Serializer>(status: &Option<Status>, serializer: S) -> Result<S::Ok, S::Error> {
+    match status {
+        Some(status) => serializer.serialize_str(status.name()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn number(part: Part) -> u32 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+// Quotes a CSV field if it contains anything that would break up the row.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn csv_row(record: &Record) -> String {
+    let answer = match &record.answer {
+        Answer::Unsolved => String::new(),
+        answer => answer.to_string(),
+    };
+    let status = record.status.as_ref().map_or("", Status::name);
+    [
+        record.day.to_string(),
+        number(record.part).to_string(),
+        csv_field(&answer),
+        (record.duration.as_nanos() as u64).to_string(),
+        csv_field(&record.input),
+        status.to_owned(),
+        csv_field(record.note.as_deref().unwrap_or("")),
+    ]
+    .join(",")
+}
+
+/// Prints what comes before the first day, if anything.
+pub fn print_start(format: Format) {
+    if format == Format::Csv {
+        println!("day,part,answer,duration_ns,input,status,note");
+    }
+}
+
+/// Prints the records of a single day.
+pub fn print_day(format: Format, number: u32, title: &str, records: &[Record]) {
+    match format {
+        Format::Text => {
+            println!("=== Advent of Code Day {}: {} ===", number, title);
+            for record in records {
+                match &record.status {
+                    Some(status) => println!("{}: {} {}", record.part, record.answer, status),
+                    None => println!("{}: {}", record.part, record.answer),
+                }
+                for line in record.note.iter().flat_map(|note| note.lines()) {
+                    println!("    {}", line);
+                }
+            }
+        }
+        Format::Json => {
+            for record in records {
+                println!("{}", serde_json::to_string(record).unwrap());
+            }
+        }
+        Format::Csv => {
+            for record in records {
+                println!("{}", csv_row(record));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Answer, status: Option<Status>) -> Record {
+        Record {
+            day: 13,
+            part: Part::Two,
+            answer,
+            duration: Duration::from_nanos(1500),
+            input: "day13/input".to_owned(),
+            status,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn json() {
+        let json = serde_json::to_string(&record(Answer::Number(1068781), Some(Status::Ok)));
+        assert_eq!(
+            json.unwrap(),
+            r#"{"day":13,"part":2,"answer":1068781,"duration_ns":1500,"input":"day13/input","status":"OK","note":null}"#
+        );
+        let json = serde_json::to_string(&record(Answer::Unsolved, None));
+        assert_eq!(
+            json.unwrap(),
+            r#"{"day":13,"part":2,"answer":null,"duration_ns":1500,"input":"day13/input","status":null,"note":null}"#
+        );
+        let mut unsolved = record(Answer::Unsolved, None);
+        unsolved.note = Some("No exact solution\nClosest: 2019".to_owned());
+        assert_eq!(
+            serde_json::to_string(&unsolved).unwrap(),
+            r#"{"day":13,"part":2,"answer":null,"duration_ns":1500,"input":"day13/input","status":null,"note":"No exact solution\nClosest: 2019"}"#
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let row = csv_row(&record(Answer::from("a,\"b\""), Some(Status::Unknown)));
+        assert_eq!(row, r#"13,2,"a,""b""",1500,day13/input,UNKNOWN,"#);
+        let row = csv_row(&record(Answer::Unsolved, None));
+        assert_eq!(row, "13,2,,1500,day13/input,,");
+        let mut unsolved = record(Answer::Unsolved, None);
+        unsolved.note = Some("No exact solution\nClosest: 2019".to_owned());
+        assert_eq!(
+            csv_row(&unsolved),
+            "13,2,,1500,day13/input,,\"No exact solution\nClosest: 2019\""
+        );
+    }
+}
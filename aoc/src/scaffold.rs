@@ -0,0 +1,206 @@
+// Scaffolding for a new day: creates the day's crate from the templates in `aoc/templates` and
+// registers it in the workspace, the runner and the fuzz targets.
+
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+const FUZZ_TARGET: &str = include_str!("../templates/fuzz_target.rs.in");
+const FUZZ_BIN: &str = include_str!("../templates/fuzz_bin.toml.in");
+
+/// Fills in the day number (`{{day}}`, `{{nn}}` for two digits) and title of a template. The
+/// title goes in as it is for `{{title}}`, and as a Rust string literal for `{{title_literal}}`.
+pub fn render(template: &str, day: u32, title: &str) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{nn}}", &format!("{:02}", day))
+        .replace("{{title_literal}}", &format!("{:?}", title))
+        .replace("{{title}}", title)
+}
+
+/// Adds `line` to the run of lines in `text` that `is_entry` picks out, keeping them sorted.
+pub fn insert_sorted<F>(text: &str, is_entry: F, line: &str) -> Result<String, String>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(lines[i])).collect();
+    let last = match entries.last() {
+        Some(&last) => last,
+        None => return Err("no entries to add to".to_owned()),
+    };
+    if entries.iter().any(|&i| lines[i] == line) {
+        return Err(format!("`{}` is already there", line.trim()));
+    }
+    let at = entries
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > line)
+        .unwrap_or(last + 1);
+    lines.insert(at, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Reads `path` and returns it as changed by `edit`.
+fn edited<F>(path: &Path, edit: F) -> Result<String, String>
+where
+    F: FnOnce(&str) -> Result<String, String>,
+{
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    edit(&text).map_err(|e| format!("Could not update {}: {}", path.display(), e))
+}
+
+fn create(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)
+            .map_err(|e| format!("Could not create {}: {}", directory.display(), e))?;
+    }
+    std::fs::write(path, text).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+/// Creates the crate for `day` in the workspace at `root` and registers it everywhere days are
+/// listed. Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u32, title: &str) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{:02}", day);
+    let directory = root.join(&name);
+    if directory.exists() {
+        return Err(format!("{} already exists", directory.display()));
+    }
+    // The title also ends up in comments, which it must not break out of
+    if title.chars().any(char::is_control) || title.contains("*/") {
+        return Err(format!("The title {:?} cannot go in a comment", title));
+    }
+    // Make every change in memory before writing anything, so that nothing is left half done
+    let files = [
+        root.join("Cargo.toml"),
+        root.join("aoc/Cargo.toml"),
+        root.join("aoc/src/days.rs"),
+        root.join("fuzz/Cargo.toml"),
+    ];
+    for file in files.iter() {
+        if !file.is_file() {
+            return Err(format!("{} is missing", file.display()));
+        }
+    }
+
+    let is_dependency = |line: &str| line.starts_with("day") && line.contains("{ path = ");
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+    let edits = [
+        edited(&files[0], |text| {
+            insert_sorted(
+                text,
+                |line| line.starts_with("    \"day"),
+                &format!("    \"{}\",", name),
+            )
+        })?,
+        edited(&files[1], |text| {
+            insert_sorted(text, is_dependency, &dependency)
+        })?,
+        edited(&files[2], |text| {
+            insert_sorted(
+                text,
+                |line| line.starts_with("    day!("),
+                &format!("    day!({}::Day{:02}, \"{}\"),", name, day, name),
+            )
+        })?,
+        edited(&files[3], |text| {
+            let text = insert_sorted(text, is_dependency, &dependency)?;
+            Ok(text + &render(FUZZ_BIN, day, title))
+        })?,
+    ];
+
+    let created = [
+        (directory.join("Cargo.toml"), CARGO_TOML),
+        (directory.join("src/lib.rs"), LIB_RS),
+        (directory.join("src/main.rs"), MAIN_RS),
+        (
+            root.join(format!("fuzz/fuzz_targets/parse_{}.rs", name)),
+            FUZZ_TARGET,
+        ),
+    ];
+    for (path, template) in created.iter() {
+        create(path, &render(template, day, title))?;
+    }
+    for (path, text) in files.iter().zip(&edits) {
+        create(path, text)?;
+    }
+
+    Ok(created
+        .iter()
+        .map(|(path, _)| path.clone())
+        .chain(files.iter().cloned())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_template() {
+        assert_eq!(
+            render("Day{{nn}}: {{day}} {{title}}", 7, "Handy Haversacks"),
+            "Day07: 7 Handy Haversacks"
+        );
+        assert_eq!(
+            render(
+                "const TITLE: &str = {{title_literal}};",
+                19,
+                "Monster \"Messages\" \\"
+            ),
+            "const TITLE: &str = \"Monster \\\"Messages\\\" \\\\\";"
+        );
+    }
+
+    #[test]
+    fn sorted_insert() {
+        let text = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n]\n";
+        let entry = |line: &str| line.starts_with("    \"day");
+        assert_eq!(
+            insert_sorted(text, entry, "    \"day02\",").unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\n"
+        );
+        assert_eq!(
+            insert_sorted(text, entry, "    \"day19\",").unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n    \"day19\",\n]\n"
+        );
+        assert!(insert_sorted(text, entry, "    \"day03\",").is_err());
+        assert!(insert_sorted(text, |_| false, "x").is_err());
+    }
+
+    #[test]
+    fn nothing_written_on_failure() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        for file in [
+            "Cargo.toml",
+            "aoc/Cargo.toml",
+            "aoc/src/days.rs",
+            "fuzz/Cargo.toml",
+        ] {
+            std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            std::fs::copy(workspace.join(file), root.join(file)).unwrap();
+        }
+        // The runner already lists the day, so the third of the four edits fails
+        let days = std::fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        let days = insert_sorted(
+            &days,
+            |line| line.starts_with("    day!("),
+            "    day!(day19::Day19, \"day19\"),",
+        )
+        .unwrap();
+        std::fs::write(root.join("aoc/src/days.rs"), days).unwrap();
+
+        assert!(new_day(&root, 19, "Monster Messages").is_err());
+        let cargo_toml = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let untouched = std::fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+        assert_eq!(cargo_toml, untouched);
+        assert!(!root.join("day19").exists());
+        assert!(new_day(&root, 19, "Monster */ Messages").is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,394 @@
+// Terminal viewer for the days that run a simulation: shows the state after every step, and lets
+// you step forward and back, play at a chosen speed and jump to the end.
+//
+// Keys: → or l steps forward, ← or h steps back, space plays or pauses, + and - change the
+// speed, c jumps to convergence, Home goes back to the start and q or Esc quits.
+
+use common::simulation::Simulation;
+use common::solution::Part;
+use common::ParseResult;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType};
+use crossterm::{cursor, execute, queue};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Steps taken at most when looking for the end of a simulation.
+pub const LIMIT: usize = 100_000;
+
+/// Fastest playing speed, in steps per second.
+pub const MAX_SPEED: u64 = 1000;
+
+/// Every state of a simulation seen so far, and which one is shown.
+pub struct History<S: Simulation> {
+    input: S::Input,
+    part: Part,
+    states: Vec<S::State>,
+    position: usize,
+    // Whether the last state is the end of the simulation.
+    done: bool,
+    // The earlier state the last one repeats, if the simulation goes round in a cycle.
+    cycle: Option<usize>,
+}
+
+impl<S: Simulation> History<S> {
+    pub fn new(input: S::Input, part: Part) -> Self {
+        let start = S::start(&input, part);
+        History {
+            input,
+            part,
+            states: vec![start],
+            position: 0,
+            done: false,
+            cycle: None,
+        }
+    }
+
+    /// Shows the next state, running the simulation a step if it was not seen yet. `false` if the
+    /// simulation is done.
+    pub fn forward(&mut self) -> bool {
+        if self.position + 1 == self.states.len() {
+            if self.done {
+                return false;
+            }
+            match S::step(&self.input, &self.states[self.position], self.part) {
+                Some(next) => {
+                    // Show the repeated state, but go no further
+                    self.cycle = self
+                        .states
+                        .iter()
+                        .position(|earlier| S::repeats(earlier, &next));
+                    self.done = self.cycle.is_some();
+                    self.states.push(next);
+                }
+                None => {
+                    self.done = true;
+                    return false;
+                }
+            }
+        }
+        self.position += 1;
+        true
+    }
+
+    /// Shows the previous state. `false` at the start.
+    pub fn back(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        self.position -= 1;
+        true
+    }
+
+    /// Shows the first state.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    /// Shows the last state, running the simulation to its end but not past `limit` steps.
+    /// `false` if it did not end by then. A simulation that repeats a state ends there.
+    pub fn converge(&mut self, limit: usize) -> bool {
+        while self.position < limit {
+            if !self.forward() {
+                return true;
+            }
+        }
+        self.done && self.position + 1 == self.states.len()
+    }
+
+    /// Number of steps taken to reach the shown state.
+    pub fn iteration(&self) -> usize {
+        self.position
+    }
+
+    /// Whether the shown state is the end of the simulation, as far as is known.
+    pub fn at_end(&self) -> bool {
+        self.done && self.position + 1 == self.states.len()
+    }
+
+    /// The iteration that the last state repeats, and the length of the cycle, once the
+    /// simulation turned out to go round in one.
+    pub fn cycle(&self) -> Option<(usize, usize)> {
+        self.cycle
+            .map(|start| (start, self.states.len() - 1 - start))
+    }
+
+    pub fn state(&self) -> &S::State {
+        &self.states[self.position]
+    }
+}
+
+/// A `History` of any day, for the viewer.
+pub trait Viewer {
+    fn forward(&mut self) -> bool;
+    fn back(&mut self) -> bool;
+    fn rewind(&mut self);
+    fn converge(&mut self, limit: usize) -> bool;
+    fn iteration(&self) -> usize;
+    fn at_end(&self) -> bool;
+    fn cycle(&self) -> Option<(usize, usize)>;
+    fn count(&self) -> usize;
+    fn counted(&self) -> &'static str;
+    fn render(&self) -> String;
+}
+
+impl<S: Simulation> Viewer for History<S> {
+    fn forward(&mut self) -> bool {
+        History::forward(self)
+    }
+
+    fn back(&mut self) -> bool {
+        History::back(self)
+    }
+
+    fn rewind(&mut self) {
+        History::rewind(self)
+    }
+
+    fn converge(&mut self, limit: usize) -> bool {
+        History::converge(self, limit)
+    }
+
+    fn iteration(&self) -> usize {
+        History::iteration(self)
+    }
+
+    fn at_end(&self) -> bool {
+        History::at_end(self)
+    }
+
+    fn cycle(&self) -> Option<(usize, usize)> {
+        History::cycle(self)
+    }
+
+    fn count(&self) -> usize {
+        S::count(self.state())
+    }
+
+    fn counted(&self) -> &'static str {
+        S::COUNTED
+    }
+
+    fn render(&self) -> String {
+        S::render(self.state())
+    }
+}
+
+fn open<S: Simulation + 'static>(input: &str, part: Part) -> ParseResult<Box<dyn Viewer>> {
+    Ok(Box::new(History::<S>::new(S::parse(input)?, part)))
+}
+
+/// The simulation of `day` on `input`, or `None` if that day does not run one.
+pub fn simulation(day: u32, input: &str, part: Part) -> Option<ParseResult<Box<dyn Viewer>>> {
+    match day {
+        11 => Some(open::<day11::Day11>(input, part)),
+        12 => Some(open::<day12::Day12>(input, part)),
+        17 => Some(open::<day17::Day17>(input, part)),
+        _ => None,
+    }
+}
+
+// Raw mode on the alternate screen, for as long as this lives.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        let screen = Screen;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(viewer: &dyn Viewer, title: &str, playing: bool, speed: u64) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let (width, height) = (usize::from(width), usize::from(height));
+    let status = match viewer.cycle() {
+        Some((start, length)) if viewer.at_end() => {
+            format!("repeats iteration {}, a cycle of {} steps", start, length)
+        }
+        _ if viewer.at_end() => "done".to_owned(),
+        _ if playing => "playing".to_owned(),
+        _ => "paused".to_owned(),
+    };
+    let header = format!(
+        "{} | iteration {} | {} {} | {} steps/s | {}",
+        title,
+        viewer.iteration(),
+        viewer.count(),
+        viewer.counted(),
+        speed,
+        status
+    );
+    let help = "→/l step  ←/h back  space play/pause  +/- speed  c converge  Home start  q quit";
+
+    let rendered = viewer.render();
+    let body = rendered.lines().take(height.saturating_sub(3));
+    let lines = std::iter::once(header.as_str())
+        .chain(std::iter::once(""))
+        .chain(body);
+
+    let mut stdout = io::stdout();
+    queue!(stdout, Clear(ClearType::All))?;
+    for (row, line) in lines.enumerate() {
+        let line: String = line.chars().take(width).collect();
+        queue!(stdout, cursor::MoveTo(0, row as u16), Print(line))?;
+    }
+    let help: String = help.chars().take(width).collect();
+    queue!(
+        stdout,
+        cursor::MoveTo(0, height.saturating_sub(1) as u16),
+        Print(help)
+    )?;
+    stdout.flush()
+}
+
+/// Shows `viewer` until the user quits, starting at `speed` steps per second.
+pub fn run(mut viewer: Box<dyn Viewer>, title: &str, speed: u64) -> io::Result<()> {
+    let _screen = Screen::enter()?;
+    let mut speed = speed.clamp(1, MAX_SPEED);
+    let mut playing = false;
+    let mut next_step = Instant::now();
+
+    loop {
+        draw(&*viewer, title, playing, speed)?;
+
+        let timeout = if playing {
+            next_step.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(60)
+        };
+        if !event::poll(timeout)? {
+            if playing {
+                playing = viewer.forward();
+                next_step = Instant::now() + Duration::from_millis(1000 / speed);
+            }
+            continue;
+        }
+
+        match event::read()? {
+            Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            }) => match code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break,
+                KeyCode::Right | KeyCode::Char('l') => {
+                    playing = false;
+                    viewer.forward();
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    playing = false;
+                    viewer.back();
+                }
+                KeyCode::Home => {
+                    playing = false;
+                    viewer.rewind();
+                }
+                KeyCode::Char(' ') => {
+                    playing = !playing && !viewer.at_end();
+                    next_step = Instant::now();
+                }
+                KeyCode::Char('+') | KeyCode::Char('=') => speed = (speed * 2).min(MAX_SPEED),
+                KeyCode::Char('-') => speed = (speed / 2).max(1),
+                KeyCode::Char('c') => {
+                    playing = false;
+                    viewer.converge(LIMIT);
+                }
+                _ => {}
+            },
+            // Redrawn at the top of the loop
+            Event::Resize(_, _) => {}
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHIP: &str = "F10\nN3\nF7\nR90\nF11\n";
+
+    #[test]
+    fn history() {
+        let input = <day12::Day12 as common::Solution>::parse(SHIP).unwrap();
+        let mut history = History::<day12::Day12>::new(input, Part::One);
+        assert!(!history.back());
+        assert!(history.forward());
+        assert!(history.forward());
+        assert_eq!(history.iteration(), 2);
+        assert_eq!(history.state().ship, common::Vec2::new(10, 3));
+
+        assert!(history.back());
+        assert_eq!(history.state().ship, common::Vec2::new(10, 0));
+        assert!(history.forward());
+        assert!(!history.at_end());
+
+        assert!(history.converge(LIMIT));
+        assert_eq!(history.iteration(), 5);
+        assert!(history.at_end());
+        assert!(!history.forward());
+        assert_eq!(Viewer::count(&history), 25);
+
+        history.rewind();
+        assert_eq!(history.iteration(), 0);
+        assert!(!history.at_end());
+    }
+
+    #[test]
+    fn converge_gives_up() {
+        let input = <day12::Day12 as common::Solution>::parse(SHIP).unwrap();
+        let mut history = History::<day12::Day12>::new(input, Part::Two);
+        assert!(!history.converge(3));
+        assert_eq!(history.iteration(), 3);
+        assert!(history.converge(LIMIT));
+        assert_eq!(Viewer::count(&history), 286);
+    }
+
+    #[test]
+    fn simulation_days() {
+        let seats = include_str!("../../day11/example");
+        let mut viewer = simulation(11, seats, Part::One).unwrap().unwrap();
+        assert!(viewer.converge(LIMIT));
+        assert_eq!((viewer.count(), viewer.counted()), (37, "occupied seats"));
+
+        let mut viewer = simulation(17, ".#.\n..#\n###\n", Part::Two)
+            .unwrap()
+            .unwrap();
+        assert!(viewer.converge(LIMIT));
+        assert_eq!((viewer.iteration(), viewer.count()), (6, 848));
+
+        assert!(simulation(13, "939\n7,13\n", Part::One).is_none());
+        assert!(simulation(11, "L?L\n", Part::One).unwrap().is_err());
+    }
+
+    #[test]
+    fn cycle() {
+        // The real seat layout without its 67th row, as in day 11's `oscillating` test
+        let seats: String = include_str!("../../day11/input")
+            .lines()
+            .enumerate()
+            .filter(|&(i, _)| i != 66)
+            .map(|(_, line)| format!("{}\n", line))
+            .collect();
+        let mut viewer = simulation(11, &seats, Part::One).unwrap().unwrap();
+        assert!(viewer.converge(LIMIT));
+        assert!(viewer.at_end());
+        assert!(!viewer.forward());
+        let (start, length) = viewer.cycle().unwrap();
+        assert_eq!((start + length, length), (viewer.iteration(), 2));
+    }
+}
@@ -0,0 +1,79 @@
+// Fuzzing support: the property every parser must hold on arbitrary input, and a small mutator
+// for running it on stable Rust without a fuzzing engine. The fuzz targets in `fuzz/` call
+// `parse` from libFuzzer; the runner's tests call it on mutated real and generated inputs.
+
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+/// Parses `data` as the input of `S`, panicking if the parser misbehaves.
+///
+/// Parsers may reject any input, but must do so with an error that points inside it rather than
+/// by panicking. Input that is not UTF-8 never reaches a parser and is ignored.
+pub fn parse<S: Solution>(data: &[u8]) {
+    let input = match std::str::from_utf8(data) {
+        Ok(input) => input,
+        Err(_) => return,
+    };
+    if let Err(e) = S::parse(input) {
+        // Parsers report positions in the normalized text, which may have gained a final newline
+        let lines = crate::input::normalize(input).split('\n').count();
+        assert!(
+            e.line >= 1 && e.line <= lines && e.column >= 1,
+            "Day {} reported an error outside its input: {}\n{:?}",
+            S::DAY,
+            e,
+            input
+        );
+        let _ = e.to_string();
+    }
+}
+
+// Bytes that mean something to at least one parser.
+const INTERESTING: &[u8] = b"\n\r -:,.#xX0123456789+[]=";
+
+/// Returns `data` with a few random edits: deleted, inserted, replaced or duplicated bytes, or a
+/// truncation.
+pub fn mutate(rng: &mut Rng, data: &[u8]) -> Vec<u8> {
+    let mut data = data.to_vec();
+    for _ in 0..=rng.below(4) {
+        let at = rng.below(data.len() + 1);
+        let len = 1 + rng.below(8);
+        match rng.below(5) {
+            0 => {
+                data.drain(at..(at + len).min(data.len()));
+            }
+            1 => {
+                let byte = if rng.chance(0.8) {
+                    *rng.choose(INTERESTING)
+                } else {
+                    rng.below(256) as u8
+                };
+                data.insert(at, byte);
+            }
+            2 if at < data.len() => data[at] = *rng.choose(INTERESTING),
+            3 => {
+                let chunk = data[at..(at + len).min(data.len())].to_vec();
+                let to = rng.below(data.len() + 1);
+                data.splice(to..to, chunk);
+            }
+            _ => data.truncate(at),
+        }
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutations_differ() {
+        let data = b"1-3 a: abcde\n1-3 b: cdefg\n";
+        let mut rng = Rng::new(0);
+        let changed = (0..100)
+            .filter(|_| mutate(&mut rng, data) != data.as_ref())
+            .count();
+        assert!(changed > 50);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+/// Makes random puzzle inputs of any size, to test and time a solution on more than the one
+/// real input.
+pub trait Generate: Solution {
+    /// Size of the real puzzle input, in the unit `generate` uses.
+    const SIZE: usize;
+    /// Largest size `generate` can make an input of. Some days stop where the answers would no
+    /// longer fit in an integer, or the puzzle runs out of room.
+    const MAX_SIZE: usize = usize::MAX;
+
+    /// Creates a valid, solvable input of about `size` elements: lines, passports, rules, …
+    /// depending on the day. Sizes above `MAX_SIZE` are cut down to it.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
@@ -0,0 +1,334 @@
+// Points and offsets on integer grids of two to four dimensions, compass directions and turns by
+// quarter circles.
+//
+// In two dimensions the math convention is used: `x` grows to the east and `y` to the north, and
+// turning left is counter-clockwise. `Grid` has `y` growing down instead, so there left and right
+// swap.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A point or offset in two dimensions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point or offset in three dimensions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// A point or offset in four dimensions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec4 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+    pub w: i64,
+}
+
+macro_rules! vector {
+    ($name:ident, $($field:ident),+) => {
+        impl $name {
+            pub const ZERO: $name = $name { $($field: 0),+ };
+
+            pub const fn new($($field: i64),+) -> Self {
+                $name { $($field),+ }
+            }
+
+            /// Sum of the absolute coordinates: the Manhattan distance to the origin.
+            pub fn manhattan(self) -> i64 {
+                0 $(+ self.$field.abs())+
+            }
+
+            /// Manhattan distance to `other`.
+            pub fn manhattan_distance(self, other: Self) -> i64 {
+                (self - other).manhattan()
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $name {
+            type Output = $name;
+
+            fn mul(self, factor: i64) -> $name {
+                $name { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: $name) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: $name) {
+                *self = *self - other;
+            }
+        }
+
+        impl MulAssign<i64> for $name {
+            fn mul_assign(&mut self, factor: i64) {
+                *self = *self * factor;
+            }
+        }
+    };
+}
+
+vector!(Vec2, x, y);
+vector!(Vec3, x, y, z);
+vector!(Vec4, x, y, z, w);
+
+// Every offset with coordinates from -1 to 1 except zero, with the first coordinate changing
+// slowest. `N` must be 3^dimensions - 1.
+const fn offsets<const N: usize>(dimensions: usize) -> [[i64; 4]; N] {
+    let mut offsets = [[0; 4]; N];
+    let mut count = 0;
+    let mut i = 0;
+    let mut total = 1;
+    while i < dimensions {
+        total *= 3;
+        i += 1;
+    }
+    let mut index = 0;
+    while index < total {
+        let mut rest = index;
+        let mut offset = [0; 4];
+        let mut d = dimensions;
+        while d > 0 {
+            d -= 1;
+            offset[d] = (rest % 3) as i64 - 1;
+            rest /= 3;
+        }
+        if offset[0] != 0 || offset[1] != 0 || offset[2] != 0 || offset[3] != 0 {
+            offsets[count] = offset;
+            count += 1;
+        }
+        index += 1;
+    }
+
+    offsets
+}
+
+const fn vec3s<const N: usize>(offsets: [[i64; 4]; N]) -> [Vec3; N] {
+    let mut vectors = [Vec3::ZERO; N];
+    let mut i = 0;
+    while i < N {
+        let [x, y, z, _] = offsets[i];
+        vectors[i] = Vec3::new(x, y, z);
+        i += 1;
+    }
+    vectors
+}
+
+const fn vec4s<const N: usize>(offsets: [[i64; 4]; N]) -> [Vec4; N] {
+    let mut vectors = [Vec4::ZERO; N];
+    let mut i = 0;
+    while i < N {
+        let [x, y, z, w] = offsets[i];
+        vectors[i] = Vec4::new(x, y, z, w);
+        i += 1;
+    }
+    vectors
+}
+
+impl Vec2 {
+    /// Offsets to the four orthogonal neighbours. On a `Grid` they point up, right, down and left.
+    pub const NEIGHBOURS_4: [Vec2; 4] = [
+        Vec2::new(0, -1),
+        Vec2::new(1, 0),
+        Vec2::new(0, 1),
+        Vec2::new(-1, 0),
+    ];
+
+    /// Offsets to all eight neighbours, with `y` changing slowest.
+    pub const NEIGHBOURS_8: [Vec2; 8] = [
+        Vec2::new(-1, -1),
+        Vec2::new(0, -1),
+        Vec2::new(1, -1),
+        Vec2::new(-1, 0),
+        Vec2::new(1, 0),
+        Vec2::new(-1, 1),
+        Vec2::new(0, 1),
+        Vec2::new(1, 1),
+    ];
+
+    /// Rotated about the origin by `quarter_turns` quarter circles, counter-clockwise if it is
+    /// positive and clockwise if it is negative.
+    pub fn rotate(self, quarter_turns: i64) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => Vec2::new(-self.y, self.x),
+            2 => -self,
+            _ => Vec2::new(self.y, -self.x),
+        }
+    }
+
+    /// Rotated a quarter circle counter-clockwise.
+    pub fn rotate_left(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Rotated a quarter circle clockwise.
+    pub fn rotate_right(self) -> Self {
+        self.rotate(-1)
+    }
+}
+
+impl Vec3 {
+    /// Offsets to all 26 neighbours, with `x` changing slowest.
+    pub const NEIGHBOURS: [Vec3; 26] = vec3s(offsets(3));
+
+    /// The 26 neighbours of this point.
+    pub fn neighbours(self) -> impl Iterator<Item = Vec3> {
+        Self::NEIGHBOURS.iter().map(move |&offset| self + offset)
+    }
+}
+
+impl Vec4 {
+    /// Offsets to all 80 neighbours, with `x` changing slowest.
+    pub const NEIGHBOURS: [Vec4; 80] = vec4s(offsets(4));
+
+    /// The 80 neighbours of this point.
+    pub fn neighbours(self) -> impl Iterator<Item = Vec4> {
+        Self::NEIGHBOURS.iter().map(move |&offset| self + offset)
+    }
+}
+
+/// A compass direction, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Turned by `quarter_turns` quarter circles, to the left if it is positive and to the right
+    /// if it is negative.
+    pub fn turn(self, quarter_turns: i64) -> Self {
+        Self::ALL[(self as i64 - quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn(-1)
+    }
+
+    /// A step of one in this direction, with north along positive `y`.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::North => Vec2::new(0, 1),
+            Direction::East => Vec2::new(1, 0),
+            Direction::South => Vec2::new(0, -1),
+            Direction::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec3::new(1, -2, 3);
+        let b = Vec3::new(4, 5, -6);
+        assert_eq!(a + b, Vec3::new(5, 3, -3));
+        assert_eq!(a - b, Vec3::new(-3, -7, 9));
+        assert_eq!(-a * 2, Vec3::new(-2, 4, -6));
+        assert_eq!(a.manhattan(), 6);
+        assert_eq!(a.manhattan_distance(b), 19);
+
+        let mut c = Vec4::ZERO;
+        c += Vec4::new(1, 2, 3, 4);
+        c *= 3;
+        c -= Vec4::new(0, 0, 0, 12);
+        assert_eq!(c, Vec4::new(3, 6, 9, 0));
+    }
+
+    #[test]
+    fn rotation() {
+        let v = Vec2::new(10, 4);
+        assert_eq!(v.rotate_right(), Vec2::new(4, -10));
+        assert_eq!(v.rotate_left(), Vec2::new(-4, 10));
+        assert_eq!(v.rotate(2), Vec2::new(-10, -4));
+        assert_eq!(v.rotate(-3), v.rotate(1));
+        assert_eq!(v.rotate(4), v);
+
+        for &direction in &Direction::ALL {
+            assert_eq!(
+                direction.turn_left().offset(),
+                direction.offset().rotate_left()
+            );
+            assert_eq!(
+                direction.turn_right().offset(),
+                direction.offset().rotate_right()
+            );
+            assert_eq!(direction.turn(-6), direction.turn(2));
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+    }
+
+    #[test]
+    fn neighbour_tables() {
+        assert_eq!(Vec3::NEIGHBOURS[0], Vec3::new(-1, -1, -1));
+        assert_eq!(Vec3::NEIGHBOURS[25], Vec3::new(1, 1, 1));
+        assert_eq!(Vec4::NEIGHBOURS[79], Vec4::new(1, 1, 1, 1));
+        assert!(!Vec4::NEIGHBOURS.contains(&Vec4::ZERO));
+
+        let mut all: Vec<_> = Vec4::ZERO.neighbours().collect();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 80);
+        assert!(all
+            .iter()
+            .all(|v| v.manhattan() > 0 && v.x.abs() <= 1 && v.w.abs() <= 1));
+
+        for table in &[&Vec2::NEIGHBOURS_4[..], &Vec2::NEIGHBOURS_8[..]] {
+            assert!(table
+                .iter()
+                .all(|v| v.x.abs() <= 1 && v.y.abs() <= 1 && *v != Vec2::ZERO));
+        }
+    }
+}
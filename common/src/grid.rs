@@ -0,0 +1,301 @@
+use crate::geometry::Vec2;
+use crate::parse::{ParseError, ParseResult};
+use std::fmt;
+
+/// A cell that is written as a single character in the puzzle input.
+pub trait Cell: Sized {
+    /// Describes the accepted characters, for parse errors.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+/// A rectangular map of cells, stored row by row. `x` grows to the right, `y` grows down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid size mismatch");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one row per line, turning every character into a cell with `cell`.
+    ///
+    /// All rows must be equally long and there must be at least one. Characters for which
+    /// `cell` returns `None` are reported as not being `expected`.
+    pub fn parse_with<F>(input: &str, expected: &str, mut cell: F) -> ParseResult<Self>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = match input.lines().next() {
+            Some(line) if !line.is_empty() => line.chars().count(),
+            _ => return Err(ParseError::end_of_input(input, "a row of the grid")),
+        };
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in input.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("a row of {} cells", width),
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(ParseError::at(input, &line[i..i + c.len_utf8()], expected))
+                    }
+                }
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width, height, cells))
+    }
+
+    /// Parses one row per line, using the character mapping of [`Cell`].
+    pub fn parse(input: &str) -> ParseResult<Self>
+    where
+        T: Cell,
+    {
+        Self::parse_with(input, T::EXPECTED, T::from_char)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        if self.contains(x, y) {
+            Some(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)`, or `None` if that is outside the grid.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.index(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    /// The cell at `(x, y)`, as if the grid repeats itself endlessly in every direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64);
+        let y = y.rem_euclid(self.height as i64);
+        &self.cells[y as usize * self.width + x as usize]
+    }
+
+    /// Replaces the cell at `(x, y)`. Returns `false` if that is outside the grid.
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (((i % width) as i64, (i / width) as i64), cell))
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: i64,
+        y: i64,
+        offsets: &'a [Vec2],
+    ) -> impl Iterator<Item = ((i64, i64), &'a T)> + 'a {
+        offsets.iter().filter_map(move |offset| {
+            let (x, y) = (x + offset.x, y + offset.y);
+            self.get(x, y).map(|cell| ((x, y), cell))
+        })
+    }
+
+    /// The orthogonal neighbours of `(x, y)` that lie inside the grid.
+    pub fn neighbours_4(&self, x: i64, y: i64) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.offsets(x, y, &Vec2::NEIGHBOURS_4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `(x, y)` that lie inside the grid.
+    pub fn neighbours_8(&self, x: i64, y: i64) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.offsets(x, y, &Vec2::NEIGHBOURS_8)
+    }
+
+    /// The cells seen when looking from `(x, y)` in direction `(dx, dy)`, up to the edge of the
+    /// grid. The starting cell itself is not included.
+    pub fn ray(&self, x: i64, y: i64, dx: i64, dy: i64) -> impl Iterator<Item = ((i64, i64), &T)> {
+        let mut position = (x, y);
+        std::iter::from_fn(move || {
+            if dx == 0 && dy == 0 {
+                return None;
+            }
+            position = (position.0 + dx, position.1 + dy);
+            self.get(position.0, position.1)
+                .map(|cell| (position, cell))
+        })
+    }
+
+    /// Creates a grid of the same size, with every cell converted by `f`.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Square {
+        Open,
+        Tree,
+    }
+
+    impl Cell for Square {
+        const EXPECTED: &'static str = "`.` or `#`";
+
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Square::Open),
+                '#' => Some(Square::Tree),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Square::Open => '.',
+                Square::Tree => '#',
+            }
+        }
+    }
+
+    const MAP: &str = "\
+..#
+#..
+.#.
+";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::<Square>::parse(MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(2, 0), Some(&Square::Tree));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, -1), None);
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Grid::<Square>::parse("..#\n#.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Grid::<Square>::parse("..#\n#x.\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (2, 2, "x")
+        );
+        assert!(Grid::<Square>::parse("").is_err());
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = Grid::<Square>::parse(MAP).unwrap();
+        assert_eq!(grid.get_wrapping(5, 0), &Square::Tree);
+        assert_eq!(grid.get_wrapping(-3, 4), &Square::Tree);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::<Square>::parse(MAP).unwrap();
+        assert_eq!(grid.neighbours_4(0, 0).count(), 2);
+        assert_eq!(grid.neighbours_8(0, 0).count(), 3);
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        let trees = grid
+            .neighbours_8(1, 1)
+            .filter(|(_, &cell)| cell == Square::Tree)
+            .count();
+        assert_eq!(trees, 3);
+    }
+
+    #[test]
+    fn ray() {
+        let grid = Grid::<Square>::parse(MAP).unwrap();
+        let seen: Vec<_> = grid.ray(0, 0, 1, 0).map(|(position, _)| position).collect();
+        assert_eq!(seen, vec![(1, 0), (2, 0)]);
+        let first_tree = grid
+            .ray(0, 2, 1, -1)
+            .find(|(_, &cell)| cell == Square::Tree);
+        assert_eq!(first_tree, Some(((2, 0), &Square::Tree)));
+        assert_eq!(grid.ray(1, 1, 0, 0).count(), 0);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::solution::Solution;
+use std::borrow::Cow;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// Path that means "read the puzzle input from stdin".
+pub const STDIN: &str = "-";
+
+// Input file used when nothing is passed on the command line.
+pub const DEFAULT_PATH: &str = "input";
+
+/// Reads the whole puzzle input from `path`, or from stdin if `path` is `-`.
+pub fn read<P>(path: P) -> io::Result<String>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let mut contents = String::new();
+    if path == Path::new(STDIN) {
+        io::stdin().read_to_string(&mut contents)?;
+    } else {
+        File::open(path)?.read_to_string(&mut contents)?;
+    }
+
+    Ok(contents)
+}
+
+// Byte order mark some editors put at the start of a file.
+const BOM: char = '\u{feff}';
+
+/// Brings puzzle input into the one shape the parsers expect: no byte order mark, `\n` line
+/// endings, no whitespace at the end of a line, no blank lines at the end, and a single `\n`
+/// after the last line. Every day's parser starts with this.
+///
+/// Lines are only ever shortened at the end, so line and column numbers in parse errors still
+/// match the original text, except on the first line of a file with a byte order mark.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let text = input.trim_end();
+    let clean = text.split('\n').all(|line| line == line.trim_end());
+    let ending = if text.is_empty() { "" } else { "\n" };
+    if clean && input.strip_suffix(ending) == Some(text) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = String::with_capacity(text.len() + ending.len());
+    if !text.is_empty() {
+        for line in text.split('\n') {
+            normalized.push_str(line.trim_end());
+            normalized.push('\n');
+        }
+    }
+
+    Cow::Owned(normalized)
+}
+
+/// Variants of `input` as it might arrive from other editors and systems.
+pub fn untidy(input: &str) -> Vec<String> {
+    let crlf = input.replace('\n', "\r\n");
+    vec![
+        crlf.clone(),
+        format!("{}{}", BOM, input),
+        input.replace('\n', " \t\n"),
+        format!("{}\n\n  \n", input),
+        input.trim_end_matches('\n').to_owned(),
+        format!("{}{}\r\n\r\n", BOM, crlf.replace("\r\n", " \r\n")),
+    ]
+}
+
+/// Panics unless `S` parses `input` and every untidy variant of it into the same thing.
+pub fn check_normalized<S>(input: &str)
+where
+    S: Solution,
+    S::Input: fmt::Debug,
+{
+    let parse = |input: &str| match S::parse(input) {
+        Ok(parsed) => format!("{:?}", parsed),
+        Err(e) => panic!("Day {} rejects {:?}: {}", S::DAY, input, e),
+    };
+    let expected = parse(input);
+    for variant in untidy(input) {
+        assert_eq!(parse(&variant), expected, "Day {} on {:?}", S::DAY, variant);
+    }
+}
+
+/// Name to show for `path` in messages.
+pub fn describe<P>(path: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    if path == Path::new(STDIN) {
+        PathBuf::from("<stdin>")
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// The value of the first of `names` on the command line of a day binary, given as
+/// `<name> <value>` or `<name>=<value>`.
+pub fn option_from_args(names: &[&str]) -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if names.contains(&arg.as_str()) {
+            if let Some(value) = args.next() {
+                return Some(value);
+            }
+        } else if let Some((name, value)) = arg.split_once('=') {
+            if names.contains(&name) {
+                return Some(value.to_owned());
+            }
+        }
+    }
+
+    None
+}
+
+/// Whether any of `names` is on the command line of a day binary.
+pub fn flag_from_args(names: &[&str]) -> bool {
+    std::env::args()
+        .skip(1)
+        .any(|arg| names.contains(&arg.as_str()))
+}
+
+/// Picks the input path out of the command line arguments of a day binary.
+///
+/// Accepts `--input <path>`, `--input=<path>` and `-i <path>`. Falls back to the file called
+/// `input` in the working directory.
+pub fn path_from_args() -> String {
+    option_from_args(&["--input", "-i"]).unwrap_or_else(|| DEFAULT_PATH.to_owned())
+}
+
+/// Reads the puzzle input selected on the command line, exiting with a message if that fails.
+pub fn from_args() -> String {
+    from_path(&path_from_args())
+}
+
+/// Reads the puzzle input at `path`, or stdin for `-`, exiting with a message if that fails.
+pub fn from_path(path: &str) -> String {
+    match read(path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Error: Could not read input from {}: {}", path, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tidy_input_is_borrowed() {
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n")));
+        assert!(matches!(normalize(""), Cow::Borrowed("")));
+        assert!(matches!(normalize("a "), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn untidy_input() {
+        for variant in untidy("abc\n\ndef\n") {
+            assert_eq!(normalize(&variant), "abc\n\ndef\n", "{:?}", variant);
+        }
+        assert_eq!(normalize("\n \r\n"), "");
+        assert_eq!(normalize("  x\t\r\n"), "  x\n");
+    }
+}
@@ -0,0 +1,25 @@
+// Code shared between the days and the runner.
+
+pub mod answer;
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod math;
+pub mod parse;
+pub mod reference;
+pub mod rng;
+pub mod simulation;
+pub mod solution;
+
+pub use answer::Answer;
+pub use generate::Generate;
+pub use geometry::{Direction, Vec2, Vec3, Vec4};
+pub use grid::Grid;
+pub use parse::{ParseError, ParseResult};
+pub use reference::Reference;
+pub use rng::Rng;
+pub use simulation::Simulation;
+pub use solution::{Part, Solution};
@@ -0,0 +1,144 @@
+// Diagnostics from the days, switched on from the command line.
+//
+// Days log through the macros of the `log` crate. The target of a message is the module it comes
+// from, so everything a day logs starts with the name of its crate, e.g. `day11`. By default only
+// warnings and errors are shown; `-v` adds debug output and `-vv` adds traces. A level can also be
+// set for a single day with `--log day11=trace`.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::str::FromStr;
+
+/// Which messages to show, per target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Filter {
+    /// Shows warnings, plus debug output for a `verbosity` of 1 and traces from 2 up.
+    pub fn new(verbosity: u8) -> Self {
+        let default = match verbosity {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        };
+        Filter {
+            default,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Adds comma-separated directives like `day11=trace`. A target without a level gets
+    /// `trace`.
+    pub fn add_directives(&mut self, directives: &str) -> Result<(), String> {
+        for directive in directives.split(',').filter(|d| !d.is_empty()) {
+            let (target, level) = match directive.split_once('=') {
+                Some((target, level)) => {
+                    let level = LevelFilter::from_str(level)
+                        .map_err(|_| format!("Unknown log level `{}`", level))?;
+                    (target, level)
+                }
+                None => (directive, LevelFilter::Trace),
+            };
+            self.targets.push((target.to_owned(), level));
+        }
+
+        Ok(())
+    }
+
+    /// The level for `target`: that of the most specific directive matching it.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| {
+                target == prefix
+                    || (target.starts_with(prefix.as_str())
+                        && target[prefix.len()..].starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+struct StderrLogger {
+    filter: Filter,
+}
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Sends log messages that pass `filter` to stderr. Only the first call has any effect.
+pub fn init(filter: Filter) {
+    let max_level = filter.max_level();
+    if log::set_boxed_logger(Box::new(StderrLogger { filter })).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+/// Sets up logging from the command line arguments of a day binary: `-v`, `-vv` and
+/// `--log <directives>`. Unknown log levels are reported and otherwise ignored.
+pub fn init_from_args() {
+    let mut verbosity = 0;
+    let mut directives = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--verbose" {
+            verbosity += 1;
+        } else if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v') {
+            verbosity += arg.len() - 1;
+        } else if arg == "--log" {
+            directives.extend(args.next());
+        } else if let Some(value) = arg.strip_prefix("--log=") {
+            directives.push(value.to_owned());
+        }
+    }
+
+    let mut filter = Filter::new(verbosity.min(2) as u8);
+    for directive in &directives {
+        if let Err(e) = filter.add_directives(directive) {
+            eprintln!("Warning: {}", e);
+        }
+    }
+    init(filter);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels() {
+        let mut filter = Filter::new(0);
+        assert_eq!(filter.level("day11"), LevelFilter::Warn);
+        assert_eq!(Filter::new(1).level("day11"), LevelFilter::Debug);
+        assert_eq!(Filter::new(2).level("day11"), LevelFilter::Trace);
+
+        filter.add_directives("day1=debug,day11").unwrap();
+        assert_eq!(filter.level("day1"), LevelFilter::Debug);
+        assert_eq!(filter.level("day1::parse"), LevelFilter::Debug);
+        assert_eq!(filter.level("day11"), LevelFilter::Trace);
+        assert_eq!(filter.level("day12"), LevelFilter::Warn);
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+
+        assert!(filter.add_directives("day11=loud").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+// Number theory over i128, for puzzles about cycles and remainders. Everything that can
+// overflow is checked and returns `None` instead of wrapping around.
+
+use std::convert::TryFrom;
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0. `None` if the result does not
+/// fit, which only happens for `i128::MIN` and 0 or `i128::MIN` itself.
+pub fn gcd(a: i128, b: i128) -> Option<i128> {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    i128::try_from(a).ok()
+}
+
+/// Least common multiple, never negative. `lcm(0, n)` is 0. `None` on overflow.
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    let g = gcd(a, b)?;
+    (a / g).checked_mul(b)?.checked_abs()
+}
+
+/// Extended Euclid: `(g, x, y)` with `a * x + b * y == g` and `g` the gcd of `a` and `b`.
+/// `None` if `a` or `b` is `i128::MIN`.
+pub fn extended_gcd(a: i128, b: i128) -> Option<(i128, i128, i128)> {
+    if a == i128::MIN || b == i128::MIN {
+        return None;
+    }
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        Some((-old_r, -old_x, -old_y))
+    } else {
+        Some((old_r, old_x, old_y))
+    }
+}
+
+/// `a * b` modulo `m`, in `0..m`, without overflowing for any positive `m`. `None` if `m` is not
+/// positive.
+pub fn mul_mod(a: i128, b: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    let (mut a, mut b, m) = (a.rem_euclid(m) as u128, b.rem_euclid(m) as u128, m as u128);
+    if let Some(product) = a.checked_mul(b) {
+        return Some((product % m) as i128);
+    }
+    // Double and add; every intermediate value stays below 2m, which fits in a u128
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % m;
+        }
+        a = (a + a) % m;
+        b >>= 1;
+    }
+    Some(result as i128)
+}
+
+/// `base` to the power `exponent`, modulo `m`, in `0..m`. `None` if `m` is not positive.
+pub fn mod_pow(base: i128, mut exponent: u128, m: i128) -> Option<i128> {
+    let mut base = base.checked_rem_euclid(m).filter(|_| m > 0)?;
+    let mut result = 1 % m;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, m)?;
+        }
+        base = mul_mod(base, base, m)?;
+        exponent >>= 1;
+    }
+    Some(result)
+}
+
+/// The `x` in `0..m` with `a * x` congruent to 1 modulo `m`. `None` if `m` is not positive or
+/// `a` and `m` have a common divisor.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m)?;
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m))
+}
+
+/// Chinese remainder theorem: the smallest `x >= 0` with `x` congruent to `residue` modulo
+/// `modulus` for every `(residue, modulus)`, together with the lcm of the moduli, after which the
+/// solutions repeat. The moduli need not be coprime.
+///
+/// `None` if there is no solution, a modulus is not positive, or the lcm does not fit. An empty
+/// list is solved by every number: `Some((0, 1))`.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut x: i128 = 0;
+    let mut period = 1;
+    for &(residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let residue = residue.rem_euclid(modulus);
+        // x + period * k = residue (mod modulus), solved for k
+        let g = gcd(period, modulus)?;
+        let difference = residue - x.rem_euclid(modulus);
+        if difference % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let inverse = mod_inverse(period / g, step)?;
+        let k = mul_mod(difference / g, inverse, step)?;
+        let next = period.checked_mul(step)?;
+        x = (x + period * k) % next;
+        period = next;
+    }
+
+    Some((x, period))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), Some(6));
+        assert_eq!(gcd(-12, 18), Some(6));
+        assert_eq!(gcd(0, 0), Some(0));
+        assert_eq!(gcd(0, -5), Some(5));
+        assert_eq!(gcd(i128::MIN, 0), None);
+        assert_eq!(gcd(i128::MIN, 6), Some(2));
+
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(-4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(i128::MAX, 2), None);
+    }
+
+    #[test]
+    fn extended_euclid() {
+        for &(a, b) in &[
+            (240, 46),
+            (-240, 46),
+            (17, 0),
+            (0, -17),
+            (1, 1),
+            (i128::MAX, 2),
+        ] {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(Some(g), gcd(a, b));
+            assert_eq!(a * x + b * y, g, "{} {}", a, b);
+        }
+        assert_eq!(extended_gcd(i128::MIN, 1), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(1, 0), None);
+
+        assert_eq!(mod_pow(2, 10, 1000), Some(24));
+        assert_eq!(mod_pow(-2, 3, 5), Some(2));
+        assert_eq!(mod_pow(7, 0, 1), Some(0));
+        assert_eq!(mod_pow(7, 1, 0), None);
+        // Fermat: a^(p - 1) = 1 modulo a prime p, here one that needs the slow multiplication
+        let p = 170_141_183_460_469_231_731_687_303_715_884_105_727; // 2^127 - 1
+        assert_eq!(mod_pow(3, (p - 1) as u128, p), Some(1));
+        assert_eq!(mul_mod(p - 1, p - 1, p), Some(1));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli with common factors
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[(0, 0)]), None);
+        // The day 13 example: t + offset is a multiple of every bus ID
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences: Vec<_> = buses.iter().map(|&(id, offset)| (-offset, id)).collect();
+        assert_eq!(crt(&congruences), Some((1068781, 7 * 13 * 59 * 31 * 19)));
+        assert_eq!(crt(&[(0, i128::MAX), (1, 2)]), None);
+    }
+}
@@ -0,0 +1,149 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Malformed puzzle input: where it went wrong, what was found there and what was expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Input file, if known. Parsers only see text; the caller fills this in.
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text. Empty if the input ended too early.
+    pub found: String,
+    /// What the parser expected to see instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error pointing at `part`, which must be a slice of `input`.
+    pub fn at<E>(input: &str, part: &str, expected: E) -> Self
+    where
+        E: Into<String>,
+    {
+        let start = input.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        ParseError {
+            file: None,
+            line,
+            column,
+            found: part.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error for input that stops before the parser is done with it.
+    pub fn end_of_input<E>(input: &str, expected: E) -> Self
+    where
+        E: Into<String>,
+    {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    /// Records the file the input was read from.
+    pub fn with_file<P>(mut self, file: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, reporting `expected` if it is not a valid `T`.
+pub fn value<T, E>(input: &str, token: &str, expected: E) -> ParseResult<T>
+where
+    T: FromStr,
+    E: Into<String>,
+{
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+/// Takes the next token from `tokens`, reporting `expected` at the end of `line` if there is none.
+pub fn next<'a, I, E>(
+    input: &str,
+    line: &'a str,
+    tokens: &mut I,
+    expected: E,
+) -> ParseResult<&'a str>
+where
+    I: Iterator<Item = &'a str>,
+    E: Into<String>,
+{
+    tokens
+        .next()
+        .ok_or_else(|| ParseError::at(input, &line[line.len()..], expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_token() {
+        let input = "1-3 a: abcde\n1-x b: cdefg\n";
+        let token = &input[15..16];
+        let error = ParseError::at(input, token, "a number");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.found, "x");
+        assert_eq!(error.to_string(), "2:3: expected a number, found `x`");
+    }
+
+    #[test]
+    fn end_of_input() {
+        let error = ParseError::end_of_input("939\n", "a list of bus IDs").with_file("input");
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(
+            error.to_string(),
+            "input:2:1: expected a list of bus IDs, found nothing"
+        );
+    }
+
+    #[test]
+    fn value_and_next() {
+        let input = "nop +0\nacc";
+        let line = &input[7..];
+        let mut tokens = line.split(' ');
+        let keyword = next(input, line, &mut tokens, "an operation").unwrap();
+        assert_eq!(keyword, "acc");
+        let error = next(input, line, &mut tokens, "a signed number").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(value::<i32, _>(input, &input[4..6], "a number"), Ok(0));
+    }
+}
@@ -0,0 +1,167 @@
+// Differential testing: every day keeps a slow but obviously correct reference solution next to
+// the real one, and its tests run both on generated inputs of growing size. When they disagree,
+// the test fails on the smallest input that shows it.
+
+use crate::answer::Answer;
+use crate::generate::Generate;
+use crate::rng::Rng;
+use crate::solution::{Part, PARTS};
+
+/// A naive solution to check the real one against.
+pub trait Reference: Generate {
+    /// Largest generated input the reference solves in reasonable time in a debug build.
+    const REFERENCE_SIZE: usize;
+
+    /// The answer to part one, or `None` if the reference has no practical way to find it.
+    fn reference_part_one(input: &Self::Input) -> Option<Answer>;
+    /// The answer to part two, or `None` if the reference has no practical way to find it.
+    fn reference_part_two(input: &Self::Input) -> Option<Answer>;
+}
+
+/// Number of inputs generated per size.
+pub const SEEDS: u64 = 4;
+
+// Sizes from 1 up to `max`, growing by about a quarter each step.
+fn sizes(max: usize) -> Vec<usize> {
+    let mut sizes = Vec::new();
+    let mut size = 1;
+    while size < max {
+        sizes.push(size);
+        size += (size / 4).max(1);
+    }
+    sizes.push(max.max(1));
+
+    sizes
+}
+
+/// A generated input on which the solution and the reference disagree.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    pub part: Part,
+    pub size: usize,
+    pub seed: u64,
+    pub input: String,
+    pub expected: Answer,
+    pub actual: Answer,
+}
+
+/// Runs the solution and the reference on inputs from size 1 up to `REFERENCE_SIZE`, and returns
+/// the first, and so smallest, input on which they disagree.
+pub fn compare<S: Reference>() -> Option<Mismatch> {
+    for size in sizes(S::REFERENCE_SIZE) {
+        for seed in 0..SEEDS {
+            let input = S::generate(&mut Rng::new(seed), size);
+            let parsed = match S::parse(&input) {
+                Ok(parsed) => parsed,
+                Err(e) => panic!("generated input does not parse: {}\n{}", e, input),
+            };
+            for &part in &PARTS {
+                let expected = match part {
+                    Part::One => S::reference_part_one(&parsed),
+                    Part::Two => S::reference_part_two(&parsed),
+                };
+                let expected = match expected {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+                let actual = S::solve(&parsed, part);
+                if actual != expected {
+                    return Some(Mismatch {
+                        part,
+                        size,
+                        seed,
+                        input,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Panics with the smallest input on which the solution and the reference disagree, if any.
+pub fn check<S: Reference>() {
+    if let Some(m) = compare::<S>() {
+        panic!(
+            "Day {} {} differs from the reference on a generated input of size {} (seed {}): \
+             expected {}, got {}\n{}",
+            S::DAY,
+            m.part,
+            m.size,
+            m.seed,
+            m.expected,
+            m.actual,
+            m.input
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseResult;
+    use crate::solution::Solution;
+
+    // Counts lines, but gets it wrong from five lines up.
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u32 = 0;
+        const TITLE: &'static str = "Lines";
+
+        type Input = usize;
+
+        fn parse(input: &str) -> ParseResult<usize> {
+            Ok(input.lines().count())
+        }
+
+        fn part_one(input: &usize) -> Answer {
+            (*input).min(4).into()
+        }
+
+        fn part_two(_input: &usize) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    impl Generate for Lines {
+        const SIZE: usize = 10;
+
+        fn generate(_rng: &mut Rng, size: usize) -> String {
+            "line\n".repeat(size)
+        }
+    }
+
+    impl Reference for Lines {
+        const REFERENCE_SIZE: usize = 20;
+
+        fn reference_part_one(input: &usize) -> Option<Answer> {
+            Some((*input).into())
+        }
+
+        fn reference_part_two(_input: &usize) -> Option<Answer> {
+            None
+        }
+    }
+
+    #[test]
+    fn smallest_mismatch() {
+        let mismatch = compare::<Lines>().unwrap();
+        assert_eq!(
+            (mismatch.part, mismatch.size, mismatch.seed),
+            (Part::One, 5, 0)
+        );
+        assert_eq!(mismatch.input, "line\n".repeat(5));
+        assert_eq!((mismatch.expected, mismatch.actual), (5.into(), 4.into()));
+    }
+
+    #[test]
+    fn growing_sizes() {
+        assert_eq!(sizes(1), [1]);
+        assert_eq!(sizes(10), [1, 2, 3, 4, 5, 6, 7, 8, 10]);
+        assert_eq!(*sizes(200).last().unwrap(), 200);
+    }
+}
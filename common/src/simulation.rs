@@ -0,0 +1,44 @@
+// Days whose answer is the end state of a simulation can expose the steps in between, so the
+// runner can show them one at a time.
+
+use crate::solution::{Part, Solution};
+
+/// A solution that runs a simulation, one step at a time.
+pub trait Simulation: Solution {
+    /// Everything needed to draw and continue the simulation.
+    type State: Clone;
+
+    /// What `count` counts, such as "occupied seats".
+    const COUNTED: &'static str;
+
+    /// The state before the first step, for the rules of `part`.
+    fn start(input: &Self::Input, part: Part) -> Self::State;
+    /// The state after the next step, or `None` once the simulation is done: it stopped changing
+    /// or the puzzle runs no further.
+    fn step(input: &Self::Input, state: &Self::State, part: Part) -> Option<Self::State>;
+    /// The number the puzzle asks for once the simulation is done.
+    fn count(state: &Self::State) -> usize;
+    /// The state as text, one line per row.
+    fn render(state: &Self::State) -> String;
+    /// Whether `state` is the same as the `earlier` one, so that the simulation goes round in a
+    /// cycle from there and never ends. Only simulations that can cycle need to tell.
+    fn repeats(_earlier: &Self::State, _state: &Self::State) -> bool {
+        false
+    }
+}
+
+/// Runs the simulation of `part` to the end and returns the final count, giving up after
+/// `limit` steps or once it repeats a state.
+pub fn run_to_end<S: Simulation>(input: &S::Input, part: Part, limit: usize) -> Option<usize> {
+    let mut states = vec![S::start(input, part)];
+    for _ in 0..limit {
+        let state = states.last().unwrap();
+        match S::step(input, state, part) {
+            Some(next) if states.iter().any(|earlier| S::repeats(earlier, &next)) => return None,
+            Some(next) => states.push(next),
+            None => return Some(S::count(state)),
+        }
+    }
+
+    None
+}
@@ -0,0 +1,127 @@
+use crate::answer::Answer;
+use crate::parse::ParseResult;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub const PARTS: [Part; 2] = [Part::One, Part::Two];
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part One"),
+            Part::Two => write!(f, "Part Two"),
+        }
+    }
+}
+
+/// A solved puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    /// Day of the advent calendar.
+    const DAY: u32;
+    /// Puzzle title, as shown on the puzzle page.
+    const TITLE: &'static str;
+
+    /// Day-specific representation of the puzzle input.
+    type Input;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+
+    fn solve(input: &Self::Input, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+
+    /// Why `part` has no answer for `input`, and what comes closest, shown by the day binary
+    /// when the answer is [`Answer::Unsolved`].
+    fn unsolved(_input: &Self::Input, _part: Part) -> Option<String> {
+        None
+    }
+}
+
+/// Parses `input` and explains why `part` has no answer for it, as [`Solution::unsolved`] does.
+/// `None` if there is nothing to explain or the input does not parse.
+pub fn unsolved<S: Solution>(input: &str, part: Part) -> Option<String> {
+    S::unsolved(&S::parse(input).ok()?, part)
+}
+
+/// Parses `input` and answers each of the requested `parts`, in order.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> ParseResult<Vec<Answer>> {
+    let parsed = S::parse(input)?;
+    Ok(parts.iter().map(|&part| S::solve(&parsed, part)).collect())
+}
+
+/// Answers from a single run, with the time spent on each step.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub parse: Duration,
+    pub answers: Vec<(Answer, Duration)>,
+}
+
+/// Like [`run`], but also measures how long parsing and each of the parts take.
+pub fn run_timed<S: Solution>(input: &str, parts: &[Part]) -> ParseResult<Timed> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(&parsed, part);
+            (answer, start.elapsed())
+        })
+        .collect();
+
+    Ok(Timed { parse, answers })
+}
+
+/// Sets up logging and parses the input given on the command line of a day binary, exiting
+/// with a message if it cannot be read or parsed.
+pub fn parse_from_args<S: Solution>() -> S::Input {
+    crate::logging::init_from_args();
+    parse_path::<S>(&crate::input::path_from_args())
+}
+
+/// Parses the input at `path`, or stdin for `-`, exiting with a message if it cannot be read or
+/// parsed.
+pub fn parse_path<S: Solution>(path: &str) -> S::Input {
+    match S::parse(&crate::input::from_path(path)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Error: {}", e.with_file(crate::input::describe(path)));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Entry point shared by the day binaries: solves both parts for the input given on the
+/// command line.
+pub fn main<S: Solution>() {
+    print_answers::<S>(&parse_from_args::<S>());
+}
+
+/// Prints the answers to both parts, and why a part has none if the day can tell.
+pub fn print_answers<S: Solution>(parsed: &S::Input) {
+    println!("=== Advent of Code Day {}: {} ===", S::DAY, S::TITLE);
+    for &part in &PARTS {
+        let answer = S::solve(parsed, part);
+        println!("{}: {}", part, answer);
+        if answer == Answer::Unsolved {
+            if let Some(note) = S::unsolved(parsed, part) {
+                for line in note.lines() {
+                    println!("    {}", line);
+                }
+            }
+        }
+    }
+}
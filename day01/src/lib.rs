@@ -0,0 +1,1062 @@
+//! Solution to [Day 1: Report Repair](https://adventofcode.com/2020/day/1).
+
+/*
+--- Day 1: Report Repair ---
+
+After saving Christmas five years in a row, you've decided to take a vacation at a nice resort on a
+tropical island. Surely, Christmas will go on without you.
+
+The tropical island has its own currency and is entirely cash-only. The gold coins used there have a
+little picture of a starfish; the locals just call them stars. None of the currency exchanges seem
+to have heard of them, but somehow, you'll need to find fifty of these coins by the time you arrive
+so you can pay the deposit on your room.
+
+To save your vacation, you need to get all fifty stars by December 25th.
+
+Collect stars by solving puzzles. Two puzzles will be made available on each day in the Advent
+calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star.
+Good luck!
+
+Before you leave, the Elves in accounting just need you to fix your expense report (your puzzle
+input); apparently, something isn't quite adding up.
+
+Specifically, they need you to find the two entries that sum to 2020 and then multiply those two
+numbers together.
+
+For example, suppose your expense report contained the following:
+
+1721
+979
+366
+299
+675
+1456
+
+In this list, the two entries that sum to 2020 are 1721 and 299. Multiplying them together produces
+1721 * 299 = 514579, so the correct answer is 514579.
+
+Of course, your expense report is much larger. Find the two entries that sum to 2020; what do you
+get if you multiply them together?
+*/
+
+use common::input;
+use common::parse::{self, ParseResult};
+use common::{Answer, Generate, Part, Reference, Rng, Solution};
+use log::debug;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::mem;
+
+/// Parses the expense report, one entry per line.
+pub fn parse_input(input: &str) -> ParseResult<Vec<i32>> {
+    let input = &*input::normalize(input);
+    // Lines is an iterator, we don't know how many lines there are
+    // because we parse as we go.
+    let mut entries = Vec::new();
+
+    for entry in input.lines() {
+        entries.push(parse::value(input, entry, "an expense report entry")?);
+    }
+
+    Ok(entries)
+}
+
+/*
+ * Ok, so: initial ideas
+ * 1. Brute force - sum every x with y
+ * 2. Second list with 2020 - x, check for duplicates
+ * 3. a. Sort the list: start with two pointers at the low end of the list
+ *    b. increment first pointer and check if sum is 2020
+ *    c. keep incrementing first pointer until overshoot
+ *    d. increment second pointer once, start decrementing first pointer until undershoot
+ *    e. keep going until we find 2020
+ *
+ * 2 is the fastest for pairs: a hash set of the entries seen so far. For three or more entries,
+ * sort the list, pick all but two of them in turn and find the last two with idea 3: two
+ * pointers moving in from both ends. Listing every match instead looks up the last entry in the
+ * sorted list, as the pointers would skip equal entries.
+ */
+
+/// The sum the expense report has to add up to.
+pub const TARGET: i64 = 2020;
+
+/// Entries at different positions that add up to a target, and their product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KSum {
+    /// Positions of the entries in the expense report, ascending. Entry `i` is on line `i + 1`.
+    pub indices: Vec<usize>,
+    /// The entries at `indices`.
+    pub entries: Vec<i32>,
+    /// `None` if the product does not fit in an i128.
+    pub product: Option<i128>,
+}
+
+impl KSum {
+    fn new(report: &[i32], mut indices: Vec<usize>) -> Self {
+        indices.sort_unstable();
+        let entries: Vec<i32> = indices.iter().map(|&i| report[i]).collect();
+        let product = entries.iter().try_fold(1i128, |product, &entry| {
+            product.checked_mul(i128::from(entry))
+        });
+        KSum {
+            indices,
+            entries,
+            product,
+        }
+    }
+}
+
+// Two entries of `entries` that add up to `target`, by looking up the entry each one is missing
+// among the ones before it. Returns their positions.
+fn two_sum(entries: &[i32], target: i64) -> Option<(usize, usize)> {
+    let mut seen = HashMap::new();
+    for (j, &entry) in entries.iter().enumerate() {
+        // A missing entry that does not even fit in an i64 cannot be there
+        let missing = target
+            .checked_sub(i64::from(entry))
+            .and_then(|missing| i32::try_from(missing).ok());
+        if let Some(&i) = missing.and_then(|missing| seen.get(&missing)) {
+            return Some((i, j));
+        }
+        seen.entry(entry).or_insert(j);
+    }
+
+    None
+}
+
+// Looks for entries that add up to a sum from `low` to `high` among the entries in ascending
+// order, either until the first match or for every match.
+struct Search<'a> {
+    entries: &'a [i32],
+    // Positions of the entries, sorted by entry
+    order: Vec<usize>,
+    all: bool,
+    chosen: Vec<usize>,
+    found: Vec<Vec<usize>>,
+}
+
+impl<'a> Search<'a> {
+    fn new(entries: &'a [i32], all: bool) -> Self {
+        let mut order: Vec<usize> = (0..entries.len()).collect();
+        order.sort_by_key(|&i| (entries[i], i));
+        Search {
+            entries,
+            order,
+            all,
+            chosen: Vec::new(),
+            found: Vec::new(),
+        }
+    }
+
+    fn value(&self, position: usize) -> i64 {
+        i64::from(self.entries[self.order[position]])
+    }
+
+    // Records a match of the chosen entries and those at `positions`. Returns whether to stop.
+    fn found(&mut self, positions: &[usize]) -> bool {
+        let mut indices = self.chosen.clone();
+        indices.extend(positions.iter().map(|&p| self.order[p]));
+        self.found.push(indices);
+        !self.all
+    }
+
+    // Finds `k` entries at positions `from..` that add up to `low..=high`. Tries every lowest
+    // entry and recurses on the entries after it, down to two entries found with two pointers
+    // when only the first match is wanted, or else down to a single entry, which is looked up in
+    // the sorted entries. Returns whether to stop.
+    fn run(&mut self, from: usize, k: usize, low: i64, high: i64) -> bool {
+        let end = self.order.len();
+        if end - from < k {
+            return false;
+        }
+        if k == 0 {
+            return low <= 0 && 0 <= high && self.found(&[]);
+        }
+        if k == 2 && !self.all {
+            let (mut i, mut j) = (from, end - 1);
+            while i < j {
+                let sum = self.value(i) + self.value(j);
+                if sum < low {
+                    i += 1;
+                } else if sum > high {
+                    j -= 1;
+                } else {
+                    return self.found(&[i, j]);
+                }
+            }
+            return false;
+        }
+        if k == 1 {
+            let first =
+                from + self.order[from..].partition_point(|&i| i64::from(self.entries[i]) < low);
+            let last =
+                from + self.order[from..].partition_point(|&i| i64::from(self.entries[i]) <= high);
+            for p in first..last {
+                if self.found(&[p]) {
+                    return true;
+                }
+            }
+            return false;
+        }
+
+        let sum = |search: &Self, range: std::ops::Range<usize>| -> i64 {
+            range.map(|p| search.value(p)).sum()
+        };
+        let largest = sum(self, end - (k - 1)..end);
+        for p in from..=end - k {
+            // Only the first of equal lowest entries can lead to a new match
+            if !self.all && p > from && self.value(p) == self.value(p - 1) {
+                continue;
+            }
+            // Every sum from here on is too large
+            if sum(self, p..p + k) > high {
+                break;
+            }
+            // Even the largest sum with this lowest entry is too small
+            if self.value(p) + largest < low {
+                continue;
+            }
+            self.chosen.push(self.order[p]);
+            let value = self.value(p);
+            let stop = self.run(
+                p + 1,
+                k - 1,
+                low.saturating_sub(value),
+                high.saturating_sub(value),
+            );
+            self.chosen.pop();
+            if stop {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+// Every set of `k` positions, or only the first found, whose entries add up to `low..=high`.
+fn search(entries: &[i32], k: usize, low: i64, high: i64, all: bool) -> Vec<Vec<usize>> {
+    let mut search = Search::new(entries, all);
+    search.run(0, k, low, high);
+    search.found
+}
+
+/// `k` entries at different positions in `entries` that add up to `target`, or `None` if there
+/// are no such entries.
+pub fn k_sum(entries: &[i32], k: usize, target: i64) -> Option<KSum> {
+    if k == 2 {
+        return two_sum(entries, target).map(|(i, j)| KSum::new(entries, vec![i, j]));
+    }
+    let indices = search(entries, k, target, target, false).pop()?;
+    Some(KSum::new(entries, indices))
+}
+
+/// Every set of `k` different positions in `entries` whose entries add up to `target`, ordered
+/// by position. An entry is never paired with itself, but equal entries at different positions
+/// are.
+pub fn all_k_sums(entries: &[i32], k: usize, target: i64) -> Vec<KSum> {
+    k_sums_within(entries, k, target, 0)
+        .into_iter()
+        .map(|near| near.sum)
+        .collect()
+}
+
+/// `sum` as a line of the report: the lines of the entries, the entries, their sum and product,
+/// and how far the sum is from `target` if it misses.
+pub fn describe(sum: &KSum, target: i64) -> String {
+    let lines: Vec<_> = sum.indices.iter().map(|i| (i + 1).to_string()).collect();
+    let terms: Vec<_> = sum.entries.iter().map(i32::to_string).collect();
+    let total: i128 = sum.entries.iter().map(|&entry| i128::from(entry)).sum();
+    let product = sum
+        .product
+        .map_or_else(|| "too large".to_owned(), |product| product.to_string());
+    let difference = if total == i128::from(target) {
+        String::new()
+    } else {
+        format!(" ({:+} from {})", total - i128::from(target), target)
+    };
+
+    format!(
+        "Lines {}: {} = {}{}, product {}",
+        lines.join(", "),
+        terms.join(" + "),
+        total,
+        difference,
+        product
+    )
+}
+
+/// What to report when no `k` entries add up to `target`: that there is no exact solution, and
+/// the closest entries, if there are `k` entries at all.
+pub fn no_exact_solution(entries: &[i32], k: usize, target: i64) -> String {
+    let mut report = format!("No exact solution: no {} entries add up to {}", k, target);
+    if let Some(near) = closest_k_sum(entries, k, target) {
+        report += &format!("\nClosest: {}", describe(&near.sum, target));
+    }
+
+    report
+}
+
+/// Entries whose sum is near a target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Near {
+    pub sum: KSum,
+    /// The sum of the entries minus the target. An i128, as it need not fit in an i64.
+    pub difference: i128,
+}
+
+impl Near {
+    fn new(entries: &[i32], indices: Vec<usize>, target: i64) -> Self {
+        let sum = KSum::new(entries, indices);
+        let total: i128 = sum.entries.iter().map(|&entry| i128::from(entry)).sum();
+        Near {
+            sum,
+            difference: total - i128::from(target),
+        }
+    }
+}
+
+/// Every set of `k` different positions in `entries` whose entries add up to at most
+/// `tolerance` away from `target`, closest first and then by position.
+pub fn k_sums_within(entries: &[i32], k: usize, target: i64, tolerance: u64) -> Vec<Near> {
+    let tolerance = i64::try_from(tolerance).unwrap_or(i64::MAX);
+    let (low, high) = (
+        target.saturating_sub(tolerance),
+        target.saturating_add(tolerance),
+    );
+    let mut near: Vec<Near> = search(entries, k, low, high, true)
+        .into_iter()
+        .map(|indices| Near::new(entries, indices, target))
+        .collect();
+    near.sort_by(|a, b| {
+        (a.difference.abs(), &a.sum.indices).cmp(&(b.difference.abs(), &b.sum.indices))
+    });
+
+    near
+}
+
+// Keeps the `k` entries at positions `from..` of `search` whose sum is closest to `target` in
+// `best`, together with the entries already in `chosen`. Works in i128, where the differences
+// to any i64 target fit.
+fn closest_from(
+    search: &Search,
+    from: usize,
+    k: usize,
+    target: i128,
+    chosen: &mut Vec<usize>,
+    best: &mut Option<(i128, Vec<usize>)>,
+) {
+    let end = search.order.len();
+    if k == 2 {
+        // Two pointers moving in from both ends: a larger sum needs a larger low entry and a
+        // smaller one a smaller high entry
+        let (mut low, mut high) = (from, end - 1);
+        while low < high {
+            let difference =
+                i128::from(search.value(low)) + i128::from(search.value(high)) - target;
+            if best
+                .as_ref()
+                .is_none_or(|(closest, _)| difference.abs() < closest.abs())
+            {
+                let mut indices = chosen.clone();
+                indices.extend(&[search.order[low], search.order[high]]);
+                *best = Some((difference, indices));
+            }
+            match difference {
+                0 => return,
+                d if d < 0 => low += 1,
+                _ => high -= 1,
+            }
+        }
+        return;
+    }
+
+    for p in from..=end - k {
+        chosen.push(search.order[p]);
+        let rest = target - i128::from(search.value(p));
+        closest_from(search, p + 1, k - 1, rest, chosen, best);
+        chosen.pop();
+        if best
+            .as_ref()
+            .is_some_and(|(difference, _)| *difference == 0)
+        {
+            return;
+        }
+    }
+}
+
+/// The `k` entries at different positions in `entries` whose sum is closest to `target`, or
+/// `None` if there are fewer than `k` entries. The first found of equally close sums.
+pub fn closest_k_sum(entries: &[i32], k: usize, target: i64) -> Option<Near> {
+    if entries.len() < k {
+        return None;
+    }
+    let search = Search::new(entries, false);
+    let indices = match k {
+        0 => vec![],
+        1 => {
+            let p = (0..entries.len())
+                .min_by_key(|&p| (i128::from(search.value(p)) - i128::from(target)).abs())
+                .unwrap();
+            vec![search.order[p]]
+        }
+        _ => {
+            let mut best = None;
+            let target = i128::from(target);
+            closest_from(&search, 0, k, target, &mut Vec::new(), &mut best);
+            best?.1
+        }
+    };
+
+    Some(Near::new(entries, indices, target))
+}
+
+/*
+ * Subsets of any size. With few entries, split them in two halves, list the sums of every subset
+ * of each half and match them up: 2^(n/2) sums per half instead of 2^n subsets. With more entries,
+ * none negative and a target small enough for the table to fit in `TABLE_LIMIT` bytes, a table
+ * over every sum up to the target keeps the fewest entries that reach it. Otherwise the halves are
+ * matched up as long as there are not too many entries.
+ */
+
+/// Most bytes a table for subset sums may take.
+pub const TABLE_LIMIT: usize = 1 << 26;
+
+/// Most entries for matching the subset sums of two halves.
+pub const HALVES_LIMIT: usize = 40;
+
+/// Most entries for which the halves are always matched up, as their subset sums take less room
+/// than a table would.
+pub const FEW_ENTRIES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Method {
+    Table,
+    Halves,
+}
+
+// Bytes taken by the table for `target`: the fewest entries for every sum and whether each entry
+// is part of them, or with `count` the number of subsets for every sum. `None` if that does not
+// fit in memory at all.
+fn table_bytes(entries: &[i32], target: i64, count: bool) -> Option<usize> {
+    let width = usize::try_from(target).ok()?.checked_add(1)?;
+    if count {
+        width.checked_mul(mem::size_of::<u128>())
+    } else {
+        let fewest = width.checked_mul(mem::size_of::<usize>())?;
+        let took = width.checked_mul(entries.len())?;
+        fewest.checked_add(took)
+    }
+}
+
+fn method(entries: &[i32], target: i64, count: bool) -> Result<Method, String> {
+    // Nothing to fill in for a negative target
+    let table = entries.iter().all(|&entry| entry >= 0)
+        && (target < 0
+            || table_bytes(entries, target, count).is_some_and(|bytes| bytes <= TABLE_LIMIT));
+    if entries.len() <= FEW_ENTRIES {
+        Ok(Method::Halves)
+    } else if table {
+        Ok(Method::Table)
+    } else if entries.len() <= HALVES_LIMIT {
+        Ok(Method::Halves)
+    } else {
+        Err(format!(
+            "{} entries are too many to search for subsets adding up to {}",
+            entries.len(),
+            target
+        ))
+    }
+}
+
+// The fewest entries that add up to `target`, by filling in the fewest entries needed for every
+// sum up to the target. Only for entries that are not negative.
+fn table_subset(entries: &[i32], target: i64) -> Option<Vec<usize>> {
+    let target = usize::try_from(target).ok()?;
+    let width = target + 1;
+    let mut fewest = vec![usize::MAX; width];
+    fewest[0] = 0;
+    // Whether entry `i` is part of the fewest entries for sum `s`, at `i * width + s`
+    let mut took = vec![false; entries.len() * width];
+    for (i, &entry) in entries.iter().enumerate() {
+        let entry = entry as usize;
+        for sum in (entry..width).rev() {
+            let with = fewest[sum - entry].saturating_add(1);
+            if with < fewest[sum] {
+                fewest[sum] = with;
+                took[i * width + sum] = true;
+            }
+        }
+    }
+    if fewest[target] == usize::MAX {
+        return None;
+    }
+
+    let mut indices = Vec::new();
+    let mut sum = target;
+    for i in (0..entries.len()).rev() {
+        if took[i * width + sum] {
+            indices.push(i);
+            sum -= entries[i] as usize;
+        }
+    }
+
+    Some(indices)
+}
+
+fn table_count(entries: &[i32], target: i64) -> Option<u128> {
+    let target = match usize::try_from(target) {
+        Ok(target) => target,
+        Err(_) => return Some(0),
+    };
+    let mut counts = vec![0u128; target + 1];
+    counts[0] = 1;
+    for &entry in entries {
+        let entry = entry as usize;
+        for sum in (entry..=target).rev() {
+            counts[sum] = counts[sum].checked_add(counts[sum - entry])?;
+        }
+    }
+
+    Some(counts[target])
+}
+
+// The sum of every subset of `entries`, indexed by the bit mask of the subset.
+fn subset_sums(entries: &[i32]) -> Vec<i64> {
+    let mut sums = vec![0i64; 1 << entries.len()];
+    for mask in 1..sums.len() {
+        let lowest = mask.trailing_zeros() as usize;
+        sums[mask] = sums[mask & (mask - 1)] + i64::from(entries[lowest]);
+    }
+
+    sums
+}
+
+// For every subset sum of the first half: the smallest subset and the number of subsets. Then
+// every subset of the second half looks up the sum it is missing.
+fn halves(entries: &[i32], target: i64) -> (Option<Vec<usize>>, u128) {
+    let (left, right) = entries.split_at(entries.len() / 2);
+    let mut sums: HashMap<i64, (u32, usize, u128)> = HashMap::new();
+    for (mask, sum) in subset_sums(left).into_iter().enumerate() {
+        let size = mask.count_ones();
+        let entry = sums.entry(sum).or_insert((size, mask, 0));
+        if size < entry.0 {
+            *entry = (size, mask, entry.2);
+        }
+        entry.2 += 1;
+    }
+
+    let mut best: Option<(u32, usize, usize)> = None;
+    let mut count = 0;
+    for (mask, sum) in subset_sums(right).into_iter().enumerate() {
+        if let Some(&(size, left_mask, n)) = sums.get(&(target - sum)) {
+            count += n;
+            let size = size + mask.count_ones();
+            if best.is_none_or(|(fewest, _, _)| size < fewest) {
+                best = Some((size, left_mask, mask));
+            }
+        }
+    }
+
+    let subset = best.map(|(_, left_mask, right_mask)| {
+        (0..left.len())
+            .filter(|i| left_mask >> i & 1 == 1)
+            .chain(
+                (0..right.len())
+                    .filter(|i| right_mask >> i & 1 == 1)
+                    .map(|i| i + left.len()),
+            )
+            .collect()
+    });
+    (subset, count)
+}
+
+/// The fewest entries at different positions that add up to `target`, in any number, or `None`
+/// if no entries do. An error if there are too many entries, or too large a target, to search.
+pub fn subset_sum(entries: &[i32], target: i64) -> Result<Option<KSum>, String> {
+    let indices = match method(entries, target, false)? {
+        Method::Table => table_subset(entries, target),
+        Method::Halves => halves(entries, target).0,
+    };
+
+    Ok(indices.map(|indices| KSum::new(entries, indices)))
+}
+
+/// Number of sets of different positions whose entries add up to `target`, counting the empty
+/// set if `target` is 0. An error if there are too many entries, or too large a target, to
+/// search, or too many subsets to count.
+pub fn count_subsets(entries: &[i32], target: i64) -> Result<u128, String> {
+    match method(entries, target, true)? {
+        Method::Table => table_count(entries, target)
+            .ok_or_else(|| format!("Too many subsets add up to {} to count", target)),
+        Method::Halves => Ok(halves(entries, target).1),
+    }
+}
+
+/// Product of the two entries that sum to 2020, or `None` if no two entries do.
+pub fn part_one(entries: &[i32]) -> Option<i128> {
+    let sum = k_sum(entries, 2, TARGET)?;
+    debug!("{:?} = {:?}", sum.entries, sum.product);
+    sum.product
+}
+
+/*
+ * --- Part Two ---
+ *
+ * The Elves in accounting are thankful for your help; one of them even offers you a starfish coin
+ * they had left over from a past vacation. They offer you a second one if you can find three
+ * numbers in your expense report that meet the same criteria.
+ *
+ * Using the above example again, the three entries that sum to 2020 are 979, 366, and 675.
+ * Multiplying them together produces the answer, 241861950.
+ *
+ * In your expense report, what is the product of the three entries that sum to 2020?
+ */
+
+/// Product of the three entries that sum to 2020, or `None` if no three entries do.
+pub fn part_two(entries: &[i32]) -> Option<i128> {
+    let sum = k_sum(entries, 3, TARGET)?;
+    debug!("{:?} = {:?}", sum.entries, sum.product);
+    sum.product
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Report Repair";
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+
+    fn unsolved(entries: &Self::Input, part: Part) -> Option<String> {
+        let k = match part {
+            Part::One => 2,
+            Part::Two => 3,
+        };
+        Some(no_exact_solution(entries, k, TARGET))
+    }
+}
+
+// Whether exactly one pair and exactly one triple of `values` sum to 2020.
+fn unique_sums(values: &[i32]) -> bool {
+    let n = values.len();
+    let mut pairs = 0;
+    let mut triples = 0;
+    for i in 0..n {
+        for j in i + 1..n {
+            if values[i] + values[j] == 2020 {
+                pairs += 1;
+            }
+            for k in j + 1..n {
+                if values[i] + values[j] + values[k] == 2020 {
+                    triples += 1;
+                }
+            }
+        }
+    }
+
+    pairs == 1 && triples == 1
+}
+
+impl Generate for Day01 {
+    const SIZE: usize = 200;
+
+    // Plants one pair and one triple that sum to 2020. Every other entry is more than 1010, so
+    // it can only complete a sum with the planted entries, and values that would are skipped.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let planted = loop {
+            let a = rng.range(1..=1009) as i32;
+            let x = rng.range(1..=672) as i32;
+            let y = rng.range(1..=672) as i32;
+            let values = vec![a, 2020 - a, x, y, 2020 - x - y];
+            if unique_sums(&values) {
+                break values;
+            }
+        };
+
+        let mut forbidden: HashSet<i32> = planted.iter().copied().collect();
+        for (i, s) in planted.iter().enumerate() {
+            forbidden.insert(2020 - s);
+            for t in &planted[i + 1..] {
+                forbidden.insert(2020 - s - t);
+            }
+        }
+
+        let mut entries = planted;
+        let upper = 1010 + 1010.max(3 * size as i64);
+        while entries.len() < size {
+            let value = rng.range(1011..=upper) as i32;
+            if forbidden.insert(value) {
+                entries.push(value);
+            }
+        }
+        rng.shuffle(&mut entries);
+
+        entries.iter().map(|entry| format!("{}\n", entry)).collect()
+    }
+}
+
+// Tries every pair and every triple of entries.
+impl Reference for Day01 {
+    const REFERENCE_SIZE: usize = 100;
+
+    fn reference_part_one(entries: &Self::Input) -> Option<Answer> {
+        for (i, a) in entries.iter().enumerate() {
+            for b in &entries[i + 1..] {
+                if a + b == 2020 {
+                    return Some((a * b).into());
+                }
+            }
+        }
+
+        Some(Answer::Unsolved)
+    }
+
+    fn reference_part_two(entries: &Self::Input) -> Option<Answer> {
+        for (i, a) in entries.iter().enumerate() {
+            for (j, b) in entries.iter().enumerate().skip(i + 1) {
+                for c in &entries[j + 1..] {
+                    if a + b + c == 2020 {
+                        return Some((a * b * c).into());
+                    }
+                }
+            }
+        }
+
+        Some(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1721
+979
+366
+299
+675
+1456
+";
+
+    #[test]
+    fn example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part_one(&input), Answer::Number(514579));
+        assert_eq!(Day01::part_two(&input), Answer::Number(241861950));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day01::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day01::part_one(&input), Answer::Number(270144));
+        assert_eq!(Day01::part_two(&input), Answer::Number(261342720));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day01::parse(&Day01::generate(&mut Rng::new(seed), 50)).unwrap();
+            assert_eq!(input.len(), 50);
+            assert!(Day01::part_one(&input) != Answer::Unsolved);
+            assert!(Day01::part_two(&input) != Answer::Unsolved);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day01>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day01>(EXAMPLE);
+    }
+
+    #[test]
+    fn k_sums() {
+        let entries = Day01::parse(EXAMPLE).unwrap();
+        let sum = k_sum(&entries, 2, TARGET).unwrap();
+        assert_eq!((sum.indices, sum.entries), (vec![0, 3], vec![1721, 299]));
+        assert_eq!(sum.product, Some(514579));
+        assert_eq!(k_sum(&entries, 3, TARGET).unwrap().entries, [979, 366, 675]);
+        assert_eq!(k_sum(&entries, 1, 979).unwrap().product, Some(979));
+        assert_eq!(k_sum(&entries, 0, 0).unwrap().product, Some(1));
+        assert_eq!(k_sum(&entries, 0, 1), None);
+        // 1721 + 979 + 366 + 299 + 675 + 1456
+        assert_eq!(k_sum(&entries, 6, 5496).unwrap().entries.len(), 6);
+        assert_eq!(k_sum(&entries, 7, 5496), None);
+        assert_eq!(k_sum(&entries, 4, 1), None);
+
+        // An entry can only be used once, but equal entries can be used together
+        assert_eq!(k_sum(&[1010, 5], 2, 2020), None);
+        assert_eq!(k_sum(&[1010, 5, 1010], 2, 2020).unwrap().indices, [0, 2]);
+        assert_eq!(k_sum(&[7, 7, 1, 7], 3, 21).unwrap().indices, [0, 1, 3]);
+
+        let sum = k_sum(&[-5, 10, -3, 8, i32::MAX, i32::MAX], 4, 10).unwrap();
+        assert_eq!(sum.entries, [-5, 10, -3, 8]);
+        assert_eq!(sum.product, Some(1200));
+        let sum = k_sum(&[i32::MAX; 5], 5, 5 * i64::from(i32::MAX)).unwrap();
+        assert_eq!(sum.product, None);
+        assert_eq!(k_sum(&[1, -1], 2, i64::MIN), None);
+        assert_eq!(k_sum(&[1, -1], 2, i64::MAX), None);
+    }
+
+    // Every set of `k` positions, tried one by one.
+    fn brute_force(entries: &[i32], k: usize, target: i64) -> Vec<Vec<usize>> {
+        let mut sets: Vec<Vec<usize>> = vec![vec![]];
+        for _ in 0..k {
+            sets = sets
+                .iter()
+                .flat_map(|set| {
+                    let from = set.last().map_or(0, |&last| last + 1);
+                    (from..entries.len()).map(move |i| [&set[..], &[i]].concat())
+                })
+                .collect();
+        }
+        sets.retain(|set| set.iter().map(|&i| i64::from(entries[i])).sum::<i64>() == target);
+        sets
+    }
+
+    #[test]
+    fn all_matches() {
+        let entries = [1010, 5, 1010, 2015, 1010, 5];
+        let pairs: Vec<_> = all_k_sums(&entries, 2, 2020)
+            .into_iter()
+            .map(|sum| sum.indices)
+            .collect();
+        assert_eq!(
+            pairs,
+            [[0, 2], [0, 4], [1, 3], [2, 4], [3, 5]]
+                .iter()
+                .map(|pair| pair.to_vec())
+                .collect::<Vec<_>>()
+        );
+        assert!(all_k_sums(&[1010], 2, 2020).is_empty());
+        assert_eq!(all_k_sums(&entries, 1, 5).len(), 2);
+
+        let mut rng = Rng::new(1);
+        for _ in 0..200 {
+            let entries: Vec<i32> = (0..rng.range(0..=12))
+                .map(|_| rng.range(0..=12) as i32 - 4)
+                .collect();
+            let k = rng.range(0..=5) as usize;
+            let target = rng.range(0..=20) - 6;
+            let all: Vec<_> = all_k_sums(&entries, k, target)
+                .into_iter()
+                .map(|sum| sum.indices)
+                .collect();
+            assert_eq!(
+                all,
+                brute_force(&entries, k, target),
+                "{:?} {} {}",
+                entries,
+                k,
+                target
+            );
+            assert_eq!(
+                k_sum(&entries, k, target).is_some(),
+                !all.is_empty(),
+                "{:?} {} {}",
+                entries,
+                k,
+                target
+            );
+        }
+    }
+
+    // The smallest subset and the number of subsets of `entries` that add up to `target`, by
+    // trying every subset.
+    fn brute_force_subsets(entries: &[i32], target: i64) -> (Option<usize>, u128) {
+        let sums = subset_sums(entries);
+        let matching = (0..sums.len()).filter(|&mask| sums[mask] == target);
+        let fewest = matching
+            .clone()
+            .map(|mask| mask.count_ones() as usize)
+            .min();
+        (fewest, matching.count() as u128)
+    }
+
+    #[test]
+    fn subsets() {
+        let entries = Day01::parse(EXAMPLE).unwrap();
+        let sum = subset_sum(&entries, TARGET).unwrap().unwrap();
+        assert_eq!(sum.entries, [1721, 299]);
+        assert_eq!(count_subsets(&entries, TARGET), Ok(2));
+        // 979 + 366 + 675, or 1721 + 299 + 979
+        let sum = subset_sum(&entries, 2999).unwrap().unwrap();
+        assert_eq!(sum.entries.iter().map(|&e| i64::from(e)).sum::<i64>(), 2999);
+        assert_eq!(sum.indices.len(), 3);
+        assert_eq!(subset_sum(&entries, 1), Ok(None));
+        assert_eq!(subset_sum(&entries, -1), Ok(None));
+        assert_eq!(subset_sum(&entries, 0).unwrap().unwrap().indices, []);
+        assert_eq!(count_subsets(&[0, 0, 5], 5), Ok(4));
+
+        // Large values are matched up in halves
+        let large = [1 << 30, -(1 << 29), 1 << 29, 3, i32::MAX, -7];
+        assert_eq!(method(&large, 1 << 30, false), Ok(Method::Halves));
+        let sum = subset_sum(&large, 1 << 30).unwrap().unwrap();
+        assert_eq!(sum.indices, [0]);
+        assert_eq!(count_subsets(&large, 1 << 30), Ok(2));
+
+        // Few entries are matched up in halves rather than filling in a table for a large
+        // target, and the table is limited by the bytes it takes
+        assert_eq!(method(&[1], 33_554_431, true), Ok(Method::Halves));
+        assert_eq!(count_subsets(&[1], 33_554_431), Ok(0));
+        let ones = [1; FEW_ENTRIES + 1];
+        assert_eq!(method(&ones, 1 << 20, false), Ok(Method::Table));
+        assert_eq!(method(&ones, 1 << 20, true), Ok(Method::Table));
+        assert_eq!(method(&ones, 1 << 22, false), Ok(Method::Halves));
+        assert_eq!(method(&ones, 1 << 22, true), Ok(Method::Halves));
+        assert_eq!(method(&ones, -1, true), Ok(Method::Table));
+        assert_eq!(count_subsets(&ones, 1 << 22), Ok(0));
+
+        let many = vec![-1; HALVES_LIMIT + 1];
+        assert!(subset_sum(&many, 3).is_err());
+        assert!(count_subsets(&[1; 200], 100).is_err());
+
+        let mut rng = Rng::new(2);
+        for _ in 0..300 {
+            let negative = rng.chance(0.5);
+            let entries: Vec<i32> = (0..rng.range(0..=12))
+                .map(|_| rng.range(0..=15) as i32 - if negative { 5 } else { 0 })
+                .collect();
+            let target = rng.range(-3..=30);
+            let (fewest, count) = brute_force_subsets(&entries, target);
+            let context = format!("{:?} {}", entries, target);
+
+            let sum = subset_sum(&entries, target).unwrap();
+            assert_eq!(sum.as_ref().map(|s| s.indices.len()), fewest, "{}", context);
+            if let Some(sum) = sum {
+                let total: i64 = sum.entries.iter().map(|&e| i64::from(e)).sum();
+                assert_eq!(total, target, "{}", context);
+            }
+            assert_eq!(count_subsets(&entries, target), Ok(count), "{}", context);
+
+            let (subset, halves_count) = halves(&entries, target);
+            assert_eq!(subset.map(|s| s.len()), fewest, "{}", context);
+            assert_eq!(halves_count, count, "{}", context);
+            if !negative {
+                let subset = table_subset(&entries, target);
+                assert_eq!(subset.map(|s| s.len()), fewest, "{}", context);
+                assert_eq!(table_count(&entries, target), Some(count), "{}", context);
+            }
+        }
+    }
+
+    #[test]
+    fn report_without_exact_solution() {
+        let entries = Day01::parse("1000\n1019\n5\n").unwrap();
+        assert_eq!(Day01::part_one(&entries), Answer::Unsolved);
+        assert_eq!(
+            Day01::unsolved(&entries, Part::One).unwrap(),
+            "No exact solution: no 2 entries add up to 2020\n\
+             Closest: Lines 1, 2: 1000 + 1019 = 2019 (-1 from 2020), product 1019000"
+        );
+        assert_eq!(
+            Day01::unsolved(&entries, Part::Two).unwrap(),
+            "No exact solution: no 3 entries add up to 2020\n\
+             Closest: Lines 1, 2, 3: 1000 + 1019 + 5 = 2024 (+4 from 2020), product 5095000"
+        );
+        assert_eq!(
+            no_exact_solution(&entries, 4, TARGET),
+            "No exact solution: no 4 entries add up to 2020"
+        );
+    }
+
+    #[test]
+    fn near_sums() {
+        let entries = Day01::parse(EXAMPLE).unwrap();
+        let near = closest_k_sum(&entries, 2, 2000).unwrap();
+        assert_eq!((near.sum.entries, near.difference), (vec![1721, 299], 20));
+        let near = closest_k_sum(&entries, 3, TARGET).unwrap();
+        assert_eq!(
+            (near.sum.entries, near.difference),
+            (vec![979, 366, 675], 0)
+        );
+        assert_eq!(closest_k_sum(&entries, 1, 1000).unwrap().sum.entries, [979]);
+        assert_eq!(closest_k_sum(&entries, 0, 5).unwrap().difference, -5);
+        assert_eq!(closest_k_sum(&entries, 7, TARGET), None);
+        // Differences to the most extreme targets do not fit in an i64
+        for &k in &[1, 2, 3] {
+            let near = closest_k_sum(&entries, k, i64::MIN).unwrap();
+            let total: i128 = near.sum.entries.iter().map(|&e| i128::from(e)).sum();
+            assert_eq!(near.difference, total - i128::from(i64::MIN));
+            assert!(closest_k_sum(&entries, k, i64::MAX).unwrap().difference < 0);
+        }
+        assert_eq!(k_sums_within(&entries, 2, i64::MIN, u64::MAX).len(), 0);
+        assert_eq!(k_sums_within(&entries, 2, i64::MAX, u64::MAX).len(), 15);
+
+        let within: Vec<_> = k_sums_within(&entries, 2, 2000, 200)
+            .into_iter()
+            .map(|near| (near.sum.indices, near.difference))
+            .collect();
+        assert_eq!(
+            within,
+            [
+                (vec![0, 3], 20),
+                (vec![0, 2], 87),
+                (vec![4, 5], 131),
+                (vec![2, 5], -178)
+            ]
+        );
+        assert!(k_sums_within(&entries, 2, 2000, 0).is_empty());
+        assert_eq!(k_sums_within(&entries, 1, 0, u64::MAX).len(), 6);
+        assert_eq!(k_sums_within(&entries, 3, 0, u64::MAX).len(), 20);
+
+        let mut rng = Rng::new(3);
+        for _ in 0..200 {
+            let entries: Vec<i32> = (0..rng.range(0..=10))
+                .map(|_| rng.range(-20..=40) as i32)
+                .collect();
+            let k = rng.range(0..=4) as usize;
+            let target = rng.range(-30..=80);
+            let tolerance = rng.range(0..=6) as u64;
+            let context = format!("{:?} {} {} {}", entries, k, target, tolerance);
+
+            let within: Vec<_> = k_sums_within(&entries, k, target, tolerance)
+                .into_iter()
+                .map(|near| (near.difference.abs(), near.sum.indices))
+                .collect();
+            let mut expected = vec![];
+            for d in 0..=tolerance as i64 {
+                let mut sets = brute_force(&entries, k, target - d);
+                if d > 0 {
+                    sets.extend(brute_force(&entries, k, target + d));
+                }
+                sets.sort();
+                expected.extend(sets.into_iter().map(|set| (i128::from(d), set)));
+            }
+            assert_eq!(within, expected, "{}", context);
+
+            let closest = if entries.len() < k {
+                None
+            } else {
+                (0..).find(|&d| {
+                    !brute_force(&entries, k, target - d).is_empty()
+                        || !brute_force(&entries, k, target + d).is_empty()
+                })
+            };
+            let near = closest_k_sum(&entries, k, target);
+            assert_eq!(
+                near.as_ref().map(|near| near.difference.abs()),
+                closest.map(i128::from),
+                "{}",
+                context
+            );
+        }
+    }
+}
@@ -0,0 +1,461 @@
+//! Solution to [Day 2: Password Philosophy](https://adventofcode.com/2020/day/2).
+
+/*
+--- Day 2: Password Philosophy ---
+
+Your flight departs in a few days from the coastal airport; the easiest way down to the coast from
+here is via toboggan.
+
+The shopkeeper at the North Pole Toboggan Rental Shop is having a bad day. "Something's wrong with
+our computers; we can't log in!" You ask if you can take a look.
+
+Their password database seems to be a little corrupted: some of the passwords wouldn't have been
+allowed by the Official Toboggan Corporate Policy that was in effect when they were chosen.
+
+To try to debug the problem, they have created a list (your puzzle input) of passwords (according to
+the corrupted database) and the corporate policy when that password was set.
+
+For example, suppose you have the following list:
+
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+
+Each line gives the password policy and then the password. The password policy indicates the lowest
+and highest number of times a given letter must appear for the password to be valid. For example,
+1-3 a means that the password must contain a at least 1 time and at most 3 times.
+
+In the above example, 2 passwords are valid. The middle password, cdefg, is not; it contains no
+instances of b, but needs at least 1. The first and third passwords are valid: they contain one a or
+nine c, both within the limits of their respective policies.
+
+How many passwords are valid according to their policies?
+*/
+
+use common::input;
+use common::parse::{self, ParseError, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::trace;
+use regex::Regex;
+use std::collections::HashSet;
+
+/// One line of the password database: the policy, followed by the password it applies to.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub first: usize,
+    pub second: usize,
+    pub letter: char,
+    pub password: String,
+}
+
+/// Parses the password database, one [`Entry`] per line.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Entry>> {
+    let input = &*input::normalize(input);
+    let mut entries = Vec::new();
+    for entry in input.lines() {
+        // The policy, then the rest of the line is the password
+        let (policy, password) = entry
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(input, entry, "a policy and a password after `: `"))?;
+        let (range, letter) = policy
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(input, policy, "a policy like `1-3 a`"))?;
+        let (first, second) = range
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(input, range, "a range like `1-3`"))?;
+        let first = parse::value(input, first, "a number")?;
+        let second = parse::value(input, second, "a number")?;
+        let mut chars = letter.chars();
+        let letter = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err(ParseError::at(input, letter, "a single letter")),
+        };
+        if password.is_empty() {
+            return Err(ParseError::at(input, password, "a password"));
+        }
+        if let Some(i) = password.find(char::is_whitespace) {
+            return Err(ParseError::at(
+                input,
+                &password[i..],
+                "the end of the password",
+            ));
+        }
+        let password = password.to_owned();
+
+        entries.push(Entry {
+            first,
+            second,
+            letter,
+            password,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// A rule a password has to follow.
+pub trait PasswordPolicy {
+    /// Whether the password of `entry` follows this policy.
+    fn allows(&self, entry: &Entry) -> bool;
+}
+
+/// The policy of part one: the letter of the entry occurs at least `first` and at most `second`
+/// times.
+#[derive(Debug, Clone, Copy)]
+pub struct CountInRange;
+
+impl PasswordPolicy for CountInRange {
+    fn allows(&self, entry: &Entry) -> bool {
+        let count = entry
+            .password
+            .chars()
+            .filter(|&c| c == entry.letter)
+            .count();
+        entry.first <= count && count <= entry.second
+    }
+}
+
+/// The policy of part two: the letter of the entry is at exactly one of the 1-based positions
+/// `first` and `second`.
+#[derive(Debug, Clone, Copy)]
+pub struct OnePosition;
+
+impl PasswordPolicy for OnePosition {
+    fn allows(&self, entry: &Entry) -> bool {
+        // Positions outside the password never match
+        let at = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|i| entry.password.chars().nth(i))
+                == Some(entry.letter)
+        };
+        at(entry.first) ^ at(entry.second)
+    }
+}
+
+/// None of these characters occur in the password.
+#[derive(Debug, Clone)]
+pub struct Forbidden(pub String);
+
+impl PasswordPolicy for Forbidden {
+    fn allows(&self, entry: &Entry) -> bool {
+        !entry.password.chars().any(|c| self.0.contains(c))
+    }
+}
+
+/// The password has at least this many different characters.
+#[derive(Debug, Clone, Copy)]
+pub struct MinDistinct(pub usize);
+
+impl PasswordPolicy for MinDistinct {
+    fn allows(&self, entry: &Entry) -> bool {
+        entry.password.chars().collect::<HashSet<char>>().len() >= self.0
+    }
+}
+
+/// The password matches the regular expression somewhere; anchor it with `^` and `$` to match
+/// the whole password.
+#[derive(Debug, Clone)]
+pub struct Pattern(pub Regex);
+
+impl PasswordPolicy for Pattern {
+    fn allows(&self, entry: &Entry) -> bool {
+        self.0.is_match(&entry.password)
+    }
+}
+
+/// Every one of the policies allows the password.
+pub struct AllOf(pub Vec<Box<dyn PasswordPolicy>>);
+
+impl PasswordPolicy for AllOf {
+    fn allows(&self, entry: &Entry) -> bool {
+        self.0.iter().all(|policy| policy.allows(entry))
+    }
+}
+
+/// At least one of the policies allows the password.
+pub struct AnyOf(pub Vec<Box<dyn PasswordPolicy>>);
+
+impl PasswordPolicy for AnyOf {
+    fn allows(&self, entry: &Entry) -> bool {
+        self.0.iter().any(|policy| policy.allows(entry))
+    }
+}
+
+// Splits `list` at the commas that are not inside brackets or escaped with a backslash. Inside a
+// character class only `[` and `]` count, so `[(]` is a class of one parenthesis. An error if the
+// brackets do not match.
+fn split_arguments(list: &str) -> Result<Vec<&str>, String> {
+    let unbalanced = || format!("Unbalanced brackets in `{}`", list);
+    let mut arguments = Vec::new();
+    let mut depth = 0usize;
+    // Character classes open, and where the items of the innermost one start: a `]` there is
+    // one of them
+    let mut classes = 0;
+    let mut items = 0;
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in list.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => {
+                classes += 1;
+                items = i + 1;
+            }
+            '^' if classes > 0 && i == items => items = i + 1,
+            ']' if classes > 0 && i != items => classes -= 1,
+            _ if classes > 0 => {}
+            '(' | '{' => depth += 1,
+            ')' | '}' | ']' => depth = depth.checked_sub(1).ok_or_else(unbalanced)?,
+            ',' if depth == 0 => {
+                arguments.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 || classes != 0 {
+        return Err(unbalanced());
+    }
+    arguments.push(&list[start..]);
+
+    Ok(arguments)
+}
+
+/// Reads a policy from its description:
+///
+/// - `count`: [`CountInRange`], the policy of part one
+/// - `position`: [`OnePosition`], the policy of part two
+/// - `forbid:<characters>`: [`Forbidden`]
+/// - `distinct:<n>`: [`MinDistinct`]
+/// - `regex:<pattern>`: [`Pattern`]
+/// - `all(<policy>,...)` and `any(<policy>,...)`: [`AllOf`] and [`AnyOf`]
+///
+/// Within `all` and `any`, brackets in a pattern have to match, except inside a character class,
+/// and a comma has to be inside brackets or escaped.
+pub fn parse_policy(description: &str) -> Result<Box<dyn PasswordPolicy>, String> {
+    let description = description.trim();
+    let (name, argument) = match description.split_once(':') {
+        Some((name, argument)) if !name.contains('(') => (name, Some(argument)),
+        _ => (description, None),
+    };
+
+    match (name, argument) {
+        ("count", None) => Ok(Box::new(CountInRange)),
+        ("position", None) => Ok(Box::new(OnePosition)),
+        ("forbid", Some(characters)) => Ok(Box::new(Forbidden(characters.to_owned()))),
+        ("distinct", Some(n)) => n
+            .parse()
+            .map(|n| Box::new(MinDistinct(n)) as Box<dyn PasswordPolicy>)
+            .map_err(|_| format!("`{}` is not a number of characters", n)),
+        ("regex", Some(pattern)) => Regex::new(pattern)
+            .map(|regex| Box::new(Pattern(regex)) as Box<dyn PasswordPolicy>)
+            .map_err(|e| format!("Invalid pattern `{}`: {}", pattern, e)),
+        _ => {
+            let composed = |prefix: &str| {
+                description
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(')'))
+            };
+            if let Some(list) = composed("all(") {
+                let policies = split_arguments(list)?.into_iter().map(parse_policy);
+                Ok(Box::new(AllOf(policies.collect::<Result<_, _>>()?)))
+            } else if let Some(list) = composed("any(") {
+                let policies = split_arguments(list)?.into_iter().map(parse_policy);
+                Ok(Box::new(AnyOf(policies.collect::<Result<_, _>>()?)))
+            } else {
+                Err(format!("Unknown password policy `{}`", description))
+            }
+        }
+    }
+}
+
+/// Number of passwords that `policy` allows.
+pub fn count_valid(entries: &[Entry], policy: &dyn PasswordPolicy) -> usize {
+    entries
+        .iter()
+        .filter(|entry| {
+            let valid = policy.allows(entry);
+            trace!("{} {}", entry.password, if valid { "OK" } else { "FAIL" });
+            valid
+        })
+        .count()
+}
+
+/// Number of passwords that contain their letter at least `first` and at most `second` times.
+pub fn part_one(entries: &[Entry]) -> usize {
+    count_valid(entries, &CountInRange)
+}
+
+/// Number of passwords that have their letter at exactly one of the 1-based positions `first`
+/// and `second`.
+pub fn part_two(entries: &[Entry]) -> usize {
+    count_valid(entries, &OnePosition)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Password Philosophy";
+
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn random_letter(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
+
+impl Generate for Day02 {
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut lines = String::new();
+        for _ in 0..size {
+            let first = rng.range(1..=10);
+            let second = rng.range(first..=first + 10);
+            let letter = random_letter(rng);
+            // Favour the letter of the policy, so that both rules pass and fail regularly
+            let length = rng.range(1..=20);
+            let password: String = (0..length)
+                .map(|_| {
+                    if rng.chance(0.4) {
+                        letter
+                    } else {
+                        random_letter(rng)
+                    }
+                })
+                .collect();
+            lines += &format!("{}-{} {}: {}\n", first, second, letter, password);
+        }
+
+        lines
+    }
+}
+
+impl Reference for Day02 {
+    const REFERENCE_SIZE: usize = 200;
+
+    fn reference_part_one(entries: &Self::Input) -> Option<Answer> {
+        let valid = entries.iter().filter(|entry| {
+            let count = entry.password.matches(entry.letter).count();
+            entry.first <= count && count <= entry.second
+        });
+
+        Some(valid.count().into())
+    }
+
+    fn reference_part_two(entries: &Self::Input) -> Option<Answer> {
+        let valid = entries.iter().filter(|entry| {
+            let letters: Vec<char> = entry.password.chars().collect();
+            let at =
+                |position: usize| position >= 1 && letters.get(position - 1) == Some(&entry.letter);
+            at(entry.first) != at(entry.second)
+        });
+
+        Some(valid.count().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+    #[test]
+    fn example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_one(&input), Answer::Number(2));
+        assert_eq!(Day02::part_two(&input), Answer::Number(1));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day02::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day02::part_one(&input), Answer::Number(620));
+        assert_eq!(Day02::part_two(&input), Answer::Number(727));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day02::parse(&Day02::generate(&mut Rng::new(seed), 50)).unwrap();
+            assert_eq!(input.len(), 50);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day02>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day02>(EXAMPLE);
+    }
+
+    #[test]
+    fn whole_password() {
+        let entries = Day02::parse("1-3 a: ab-cde\n").unwrap();
+        assert_eq!(entries[0].password, "ab-cde");
+        let error = Day02::parse("1-3 a: abc extra\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+        assert!(Day02::parse("1-3 a:\n").is_err());
+        assert!(Day02::parse("1-3 a: \n").is_err());
+        assert!(Day02::parse("1 a: abc\n").is_err());
+    }
+
+    #[test]
+    fn policies() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        let count = |description: &str| count_valid(&input, &*parse_policy(description).unwrap());
+        assert_eq!(count("count"), 2);
+        assert_eq!(count("position"), 1);
+        assert_eq!(count("forbid:a"), 2);
+        assert_eq!(count("forbid:ae"), 1);
+        assert_eq!(count("distinct:5"), 2);
+        assert_eq!(count("distinct:6"), 0);
+        assert_eq!(count("regex:^c+$"), 1);
+        assert_eq!(count("regex:^[a-e]{5}$"), 1);
+        assert_eq!(count("all(count,position)"), 1);
+        assert_eq!(count("any(count, position)"), 2);
+        assert_eq!(count("all(any(forbid:a,distinct:5),regex:c{1,2})"), 3);
+        assert_eq!(count("any(regex:^(c,d)?c\\,,regex:[,x],forbid:b)"), 2);
+        assert_eq!(count("any(regex:[(],count)"), 2);
+        assert_eq!(count("any(regex:^[^]b]+$,regex:[[:digit:]])"), 2);
+        assert_eq!(count("all(regex:[]c],regex:a\\])"), 0);
+
+        for description in &[
+            "",
+            "count:1",
+            "distinct:x",
+            "regex:(",
+            "all(count",
+            "nope",
+            "any(count))",
+            "all(regex:(,count)",
+            "any(regex:[a,count)",
+            "all(regex:a],count)",
+        ] {
+            assert!(parse_policy(description).is_err(), "{}", description);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Solution to [Day 3: Toboggan Trajectory](https://adventofcode.com/2020/day/3).
+
+/*
+--- Day 3: Toboggan Trajectory ---
+
+With the toboggan login problems resolved, you set off toward the airport. While travel by toboggan
+might be easy, it's certainly not safe: there's very minimal steering and the area is covered in
+trees. You'll need to see which angles will take you near the fewest trees.
+
+Due to the local geology, trees in this area only grow on exact integer coordinates in a grid. You
+make a map (your puzzle input) of the open squares (.) and trees (#) you can see. For example:
+
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+
+These aren't the only trees, though; due to something you read about once involving arboreal
+genetics and biome stability, the same pattern repeats to the right many times:
+
+..##.........##.........##.........##.........##.........##.......  --->
+#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
+.#....#..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
+..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
+.#...##..#..#...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
+..#.##.......#.##.......#.##.......#.##.......#.##.......#.##.....  --->
+.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
+.#........#.#........#.#........#.#........#.#........#.#........#
+#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...
+#...##....##...##....##...##....##...##....##...##....##...##....#
+.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
+
+You start on the open square (.) in the top-left corner and need to reach the bottom (below the
+bottom-most row on your map).
+
+The toboggan can only follow a few specific slopes (you opted for a cheaper model that prefers
+rational numbers); start by counting all the trees you would encounter for the slope right 3,
+down 1:
+
+From your starting position at the top-left, check the position that is right 3 and down 1. Then,
+check the position that is right 3 and down 1 from there, and so on until you go past the bottom of
+the map.
+
+The locations you'd check in the above example are marked here with O where there was an open square
+and X where there was a tree:
+
+..##.........##.........##.........##.........##.........##.......  --->
+#..O#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
+.#....X..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
+..#.#...#O#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
+.#...##..#..X...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
+..#.##.......#.X#.......#.##.......#.##.......#.##.......#.##.....  --->
+.#.#.#....#.#.#.#.O..#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
+.#........#.#........X.#........#.#........#.#........#.#........#
+#.##...#...#.##...#...#.X#...#...#.##...#...#.##...#...#.##...#...
+#...##....##...##....##...#X....##...##....##...##....##...##....#
+.#..#...#.#.#..#...#.#.#..#...X.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
+
+In this example, traversing the map using this slope would cause you to encounter 7 trees.
+
+Starting at the top-left corner of your map and following a slope of right 3 and down 1, how many
+trees would you encounter?
+*/
+
+use common::grid::Cell;
+use common::input;
+use common::{Answer, Generate, Grid, ParseResult, Reference, Rng, Solution};
+use log::debug;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Open,
+    Tree,
+}
+
+impl Cell for Square {
+    const EXPECTED: &'static str = "`.` or `#`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Square::Open),
+            '#' => Some(Square::Tree),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Square::Open => '.',
+            Square::Tree => '#',
+        }
+    }
+}
+
+/// The part of the map in the input. It repeats itself to the right.
+pub type Map = Grid<Square>;
+
+/// Parses the map: `.` for an open square, `#` for a tree.
+pub fn parse_input(input: &str) -> ParseResult<Map> {
+    let input = &*input::normalize(input);
+    Grid::parse(input)
+}
+
+/// Number of trees hit on the way from the top-left corner to the bottom of the map, moving
+/// `right` and `down` squares at a time.
+pub fn count_trees(map: &Map, right: i64, down: i64) -> i32 {
+    let mut x = 0;
+    let mut y = 0;
+    let mut num_trees = 0;
+
+    while y < map.height() as i64 {
+        if *map.get_wrapping(x, y) == Square::Tree {
+            num_trees += 1;
+        }
+        x += right;
+        y += down;
+    }
+
+    num_trees
+}
+
+/// Number of trees hit on the slope right 3, down 1.
+pub fn part_one(map: &Map) -> i32 {
+    let right = 3;
+    let down = 1;
+
+    count_trees(map, right, down)
+}
+
+/*
+--- Part Two ---
+
+Time to check the rest of the slopes - you need to minimize the probability of a sudden arboreal
+stop, after all.
+
+Determine the number of trees you would encounter if, for each of the following slopes, you start at
+the top-left corner and traverse the map all the way to the bottom:
+
+    Right 1, down 1.
+    Right 3, down 1. (This is the slope you already checked.)
+    Right 5, down 1.
+    Right 7, down 1.
+    Right 1, down 2.
+
+In the above example, these slopes would find 2, 7, 3, 4, and 2 tree(s) respectively; multiplied
+together, these produce the answer 336.
+
+What do you get if you multiply together the number of trees encountered on each of the listed
+slopes?
+*/
+
+/// Product of the number of trees hit on each of the five slopes.
+pub fn part_two(map: &Map) -> i64 {
+    let mut product: i64 = 1;
+    for (right, down) in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter() {
+        let count = count_trees(map, *right, *down);
+        debug!(
+            "Number of trees - Right {}, down {}: {}",
+            right, down, count
+        );
+        product *= count as i64;
+    }
+
+    product
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day03 {
+    const SIZE: usize = 323;
+    // Beyond 30000 rows the product of part two could overflow
+    const MAX_SIZE: usize = 30_000;
+
+    // The size is the height of the map, which is as wide as the real one.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let height = size.clamp(1, Self::MAX_SIZE);
+        let mut map = String::new();
+        for y in 0..height {
+            for x in 0..31 {
+                // The toboggan starts on an open square
+                let tree = (x, y) != (0, 0) && rng.chance(0.2);
+                map.push(if tree { '#' } else { '.' });
+            }
+            map.push('\n');
+        }
+
+        map
+    }
+}
+
+// Walks down the map one row at a time, working out the column of every row on the slope.
+fn reference_count(map: &Map, right: usize, down: usize) -> i64 {
+    let mut trees = 0;
+    for y in (0..map.height()).step_by(down) {
+        let x = (y / down * right) % map.width();
+        if *map.get(x as i64, y as i64).unwrap() == Square::Tree {
+            trees += 1;
+        }
+    }
+
+    trees
+}
+
+impl Reference for Day03 {
+    const REFERENCE_SIZE: usize = 100;
+
+    fn reference_part_one(map: &Self::Input) -> Option<Answer> {
+        Some(reference_count(map, 3, 1).into())
+    }
+
+    fn reference_part_two(map: &Self::Input) -> Option<Answer> {
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+        let product: i64 = slopes
+            .iter()
+            .map(|&(right, down)| reference_count(map, right, down))
+            .product();
+
+        Some(product.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part_one(&input), Answer::Number(7));
+        assert_eq!(Day03::part_two(&input), Answer::Number(336));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day03::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day03::part_one(&input), Answer::Number(280));
+        assert_eq!(Day03::part_two(&input), Answer::Number(4355551200));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day03::parse(&Day03::generate(&mut Rng::new(seed), 50)).unwrap();
+            assert_eq!((input.width(), input.height()), (31, 50));
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day03>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day03>(EXAMPLE);
+    }
+}
@@ -0,0 +1,642 @@
+//! Solution to [Day 4: Passport Processing](https://adventofcode.com/2020/day/4).
+
+/*
+--- Day 4: Passport Processing ---
+
+You arrive at the airport only to realize that you grabbed your North Pole Credentials instead of
+your passport. While these documents are extremely similar, North Pole Credentials aren't issued by
+a country and therefore aren't actually valid documentation for travel in most of the world.
+
+It seems like you're not the only one having problems, though; a very long line has formed for the
+automatic passport scanners, and the delay could upset your travel itinerary.
+
+Due to some questionable network security, you realize you might be able to solve both of these
+problems at the same time.
+
+The automatic passport scanners are slow because they're having trouble detecting which passports
+have all required fields. The expected fields are as follows:
+
+    byr (Birth Year)
+    iyr (Issue Year)
+    eyr (Expiration Year)
+    hgt (Height)
+    hcl (Hair Color)
+    ecl (Eye Color)
+    pid (Passport ID)
+    cid (Country ID)
+
+Passport data is validated in batch files (your puzzle input). Each passport is represented as a
+sequence of key:value pairs separated by spaces or newlines. Passports are separated by blank lines.
+
+Here is an example batch file containing four passports:
+
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+
+The first passport is valid - all eight fields are present. The second passport is invalid - it is
+missing hgt (the Height field).
+
+The third passport is interesting; the only missing field is cid, so it looks like data from North
+Pole Credentials, not a passport at all! Surely, nobody would mind if you made the system
+temporarily ignore missing cid fields. Treat this "passport" as valid.
+
+The fourth passport is missing two fields, cid and byr. Missing cid is fine, but missing any other
+field is not, so this passport is invalid.
+
+According to the above rules, your improved system would report 2 valid passports.
+
+Count the number of valid passports - those that have all required fields. Treat cid as optional.
+In your batch file, how many passports are valid?
+*/
+use common::input;
+use common::{Answer, Generate, ParseError, ParseResult, Reference, Rng, Solution};
+use log::{debug, trace};
+use std::fmt;
+
+/// A passport from the batch file. Fields that were missing are `None`.
+pub struct Passport {
+    pub birth_year: Option<String>,      // byr
+    pub issue_year: Option<String>,      // iyr
+    pub expiration_year: Option<String>, // eyr
+    pub height: Option<String>,          // hgt
+    pub hair_color: Option<String>,      // hcl
+    pub eye_color: Option<String>,       // ecl
+    pub passport_id: Option<String>,     // pid
+    pub country_id: Option<String>,      // cid
+}
+
+impl fmt::Debug for Passport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Point")
+            .field("birth_year", &self.birth_year)
+            .field("issue_year", &self.issue_year)
+            .field("expiration_year", &self.expiration_year)
+            .field("height", &self.height)
+            .field("hair_color", &self.hair_color)
+            .field("eye_color", &self.eye_color)
+            .field("passport_id", &self.passport_id)
+            .field("country_id", &self.country_id)
+            .finish()
+    }
+}
+
+/// Parses passports that have already been split up. `list` holds the text of each passport,
+/// which must be a slice of `input`; every field is a `key:value` pair separated by whitespace.
+pub fn parse_passports(input: &str, list: &[&str]) -> ParseResult<Vec<Passport>> {
+    let mut passports = Vec::new();
+
+    for entry in list {
+        let mut birth_year: Option<String> = None;
+        let mut issue_year: Option<String> = None;
+        let mut expiration_year: Option<String> = None;
+        let mut height: Option<String> = None;
+        let mut hair_color: Option<String> = None;
+        let mut eye_color: Option<String> = None;
+        let mut passport_id: Option<String> = None;
+        let mut country_id: Option<String> = None;
+
+        /*
+         * pid:8729818647 hcl:z
+         * ecl:#ae70eb cid:168 hgt:161cm iyr:2030
+         * eyr:2020 byr:2022
+         * (possible newline)
+         */
+        let fields = entry.split([' ', '\n']);
+        for field in fields {
+            if field.is_empty() {
+                continue;
+            }
+            // pid:8729818647
+            trace!("parsing field: {}", field);
+            let (key, value) = match field.split_once(':') {
+                Some((key, value)) => (key, Some(value.to_owned())),
+                None => return Err(ParseError::at(input, field, "a `key:value` field")),
+            };
+            match key {
+                "byr" => birth_year = value,
+                "iyr" => issue_year = value,
+                "eyr" => expiration_year = value,
+                "hgt" => height = value,
+                "hcl" => hair_color = value,
+                "ecl" => eye_color = value,
+                "pid" => passport_id = value,
+                "cid" => country_id = value,
+                _ => return Err(ParseError::at(input, field, "a passport field")),
+            }
+        }
+
+        let passport = Passport {
+            birth_year,
+            issue_year,
+            expiration_year,
+            height,
+            hair_color,
+            eye_color,
+            passport_id,
+            country_id,
+        };
+
+        passports.push(passport);
+    }
+
+    Ok(passports)
+}
+
+/// Number of passports that have all required fields. `cid` is optional.
+pub fn count_complete(passports: &[Passport]) -> i32 {
+    let mut count = 0;
+
+    for x in passports {
+        if x.birth_year.is_some()
+            && x.issue_year.is_some()
+            && x.expiration_year.is_some()
+            && x.height.is_some()
+            && x.hair_color.is_some()
+            && x.eye_color.is_some()
+            && x.passport_id.is_some()
+        {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Parses the batch file. Passports are separated by blank lines.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Passport>> {
+    let input = &*input::normalize(input);
+    // Passports seem to be delimited by two newlines.
+    // Create a vector of strings for passports.
+    let passports: Vec<&str> = input.split("\n\n").collect();
+    debug!("Number of passports: {}", passports.len());
+
+    // Now we can split each password into fields.
+    parse_passports(input, &passports)
+}
+
+/*
+--- Part Two ---
+
+The line is moving more quickly now, but you overhear airport security talking about how passports
+with invalid data are getting through. Better add some data validation, quick!
+
+You can continue to ignore the cid field, but each other field has strict rules about what values
+are valid for automatic validation:
+
+    byr (Birth Year) - four digits; at least 1920 and at most 2002.
+    iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+    eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+    hgt (Height) - a number followed by either cm or in:
+        If cm, the number must be at least 150 and at most 193.
+        If in, the number must be at least 59 and at most 76.
+    hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+    ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+    pid (Passport ID) - a nine-digit number, including leading zeroes.
+    cid (Country ID) - ignored, missing or not.
+
+Your job is to count the passports where all required fields are both present and valid according to
+the above rules. Here are some example values:
+
+byr valid:   2002
+byr invalid: 2003
+
+hgt valid:   60in
+hgt valid:   190cm
+hgt invalid: 190in
+hgt invalid: 190
+
+hcl valid:   #123abc
+hcl invalid: #123abz
+hcl invalid: 123abc
+
+ecl valid:   brn
+ecl invalid: wat
+
+pid valid:   000000001
+pid invalid: 0123456789
+
+Here are some invalid passports:
+
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+
+Here are some valid passports:
+
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+
+Count the number of valid passports - those that have all required fields and valid values. Continue
+to treat cid as optional. In your batch file, how many passports are valid?
+*/
+use regex::Regex;
+
+fn regex_is_match<'t>(
+    option_string: Option<&'t str>,
+    re: &regex::Regex,
+) -> Option<regex::Match<'t>> {
+    if let Some(string) = option_string {
+        trace!("String exists: {}", string);
+        if let Some(captures) = re.captures(string) {
+            return captures.get(1);
+        }
+    }
+
+    None
+}
+
+fn regex_match(option_string: Option<&str>, re: &regex::Regex, min: i32, max: i32) -> bool {
+    if let Some(match1) = regex_is_match(option_string, re) {
+        trace!("Regex passed");
+        if let Ok(val) = match1.as_str().parse::<i32>() {
+            trace!("Checking if {} is between {} and {}", val, min, max);
+            return val >= min && val <= max;
+        }
+    }
+
+    false
+}
+
+/// Number of passports that have all required fields, each with a valid value.
+pub fn count_valid(parsed: &[Passport]) -> i32 {
+    /*
+    byr (Birth Year) - four digits; at least 1920 and at most 2002.
+    iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+    eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+    hgt (Height) - a number followed by either cm or in:
+        If cm, the number must be at least 150 and at most 193.
+        If in, the number must be at least 59 and at most 76.
+    hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+    ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+    pid (Passport ID) - a nine-digit number, including leading zeroes.
+    cid (Country ID) - ignored, missing or not.
+    */
+    // byr, iyr, eyr
+    let re_four_digits = Regex::new(r"(\d{4})").unwrap();
+    let re_height_cm = Regex::new(r"(\d+)cm").unwrap();
+    let re_height_in = Regex::new(r"(\d+)in").unwrap();
+    let re_hair_color = Regex::new(r"#([\da-f]{6})").unwrap();
+    // we're gonna use matching for the eye color.
+    let re_passport_id = Regex::new(r"(\b\d{9}\b)").unwrap();
+
+    let mut num_valid = 0;
+    for passport in parsed {
+        trace!("Validating passport: {:?}", passport);
+        if !regex_match(passport.birth_year.as_deref(), &re_four_digits, 1920, 2002) {
+            trace!("Failing birth_year {:?}", passport.birth_year);
+            continue;
+        }
+        if !regex_match(passport.issue_year.as_deref(), &re_four_digits, 2010, 2020) {
+            trace!("Failing issue_year {:?}", passport.issue_year);
+            continue;
+        }
+        if !regex_match(
+            passport.expiration_year.as_deref(),
+            &re_four_digits,
+            2020,
+            2030,
+        ) {
+            trace!("Failing expiration_year {:?}", passport.expiration_year);
+            continue;
+        }
+        if !(regex_match(passport.height.as_deref(), &re_height_cm, 150, 193)
+            || regex_match(passport.height.as_deref(), &re_height_in, 59, 76))
+        {
+            trace!("Failing height {:?}", passport.height);
+            continue;
+        }
+        if regex_is_match(passport.hair_color.as_deref(), &re_hair_color).is_none() {
+            trace!("Failing hair_color {:?}", passport.hair_color);
+            continue;
+        }
+        match passport.eye_color.as_deref() {
+            Some("amb") => {}
+            Some("blu") => {}
+            Some("brn") => {}
+            Some("gry") => {}
+            Some("grn") => {}
+            Some("hzl") => {}
+            Some("oth") => {}
+            _ => {
+                trace!("Failing eye_color {:?}", passport.eye_color);
+                continue;
+            }
+        }
+        if regex_is_match(passport.passport_id.as_deref(), &re_passport_id).is_none() {
+            trace!("Failing passport_id {:?}", passport.passport_id);
+            continue;
+        }
+        trace!("Passport OK: {:?}", passport);
+
+        num_valid += 1;
+    }
+
+    num_valid
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Passport Processing";
+
+    type Input = Vec<Passport>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    // Valid passports must contain certain values
+    fn part_one(input: &Self::Input) -> Answer {
+        count_complete(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        count_valid(input).into()
+    }
+}
+
+fn digits(rng: &mut Rng, n: usize) -> String {
+    (0..n)
+        .map(|_| (b'0' + rng.below(10) as u8) as char)
+        .collect()
+}
+
+// A value for the field `key`, which is valid if `valid` is set. Invalid values are clearly so,
+// rather than testing the edges of the rules.
+fn field_value(rng: &mut Rng, key: &str, valid: bool) -> String {
+    let year = |rng: &mut Rng, min: i64, max: i64| {
+        if valid {
+            rng.range(min..=max)
+        } else if rng.chance(0.5) {
+            rng.range(min - 20..=min - 1)
+        } else {
+            rng.range(max + 1..=max + 20)
+        }
+        .to_string()
+    };
+    match key {
+        "byr" => year(rng, 1920, 2002),
+        "iyr" => year(rng, 2010, 2020),
+        "eyr" => year(rng, 2020, 2030),
+        "hgt" => match (valid, rng.below(3)) {
+            (true, 0) => format!("{}in", rng.range(59..=76)),
+            (true, _) => format!("{}cm", rng.range(150..=193)),
+            (false, 0) => format!("{}in", rng.range(150..=193)),
+            (false, 1) => format!("{}cm", rng.range(59..=76)),
+            (false, _) => rng.range(59..=193).to_string(),
+        },
+        "hcl" => {
+            let hex: String = (0..6)
+                .map(|_| *rng.choose(b"0123456789abcdef") as char)
+                .collect();
+            if valid {
+                format!("#{}", hex)
+            } else {
+                hex
+            }
+        }
+        "ecl" => {
+            let colors: &[&str] = if valid {
+                &["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+            } else {
+                &["xry", "gmt", "utc", "zzz"]
+            };
+            rng.choose(colors).to_string()
+        }
+        "pid" => match (valid, rng.chance(0.5)) {
+            (true, _) => digits(rng, 9),
+            (false, true) => digits(rng, 8),
+            (false, false) => digits(rng, 10),
+        },
+        _ => rng.range(100..=350).to_string(),
+    }
+}
+
+impl Generate for Day04 {
+    const SIZE: usize = 285;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let keys = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+        let mut passports = Vec::new();
+        for _ in 0..size {
+            let mut fields = Vec::new();
+            for key in keys.iter() {
+                let present = if *key == "cid" { 0.5 } else { 0.9 };
+                if rng.chance(present) {
+                    let valid = rng.chance(0.85);
+                    fields.push(format!("{}:{}", key, field_value(rng, key, valid)));
+                }
+            }
+            if fields.is_empty() {
+                // An empty passport would vanish between the blank lines
+                fields.push(format!("cid:{}", field_value(rng, "cid", true)));
+            }
+            rng.shuffle(&mut fields);
+
+            let mut passport = String::new();
+            for (i, field) in fields.iter().enumerate() {
+                if i > 0 {
+                    passport.push(if rng.chance(0.3) { '\n' } else { ' ' });
+                }
+                passport += field;
+            }
+            passports.push(passport);
+        }
+
+        passports.join("\n\n") + "\n"
+    }
+}
+
+// The rules of part two, checked by hand instead of with regular expressions.
+fn reference_valid(passport: &Passport) -> bool {
+    fn number(value: &str, digits: Option<usize>, min: u32, max: u32) -> bool {
+        digits.map_or(!value.is_empty(), |digits| value.len() == digits)
+            && value.chars().all(|c| c.is_ascii_digit())
+            && value.parse().is_ok_and(|n: u32| min <= n && n <= max)
+    }
+
+    let field =
+        |field: &Option<String>, valid: &dyn Fn(&str) -> bool| field.as_deref().is_some_and(valid);
+    field(&passport.birth_year, &|v| number(v, Some(4), 1920, 2002))
+        && field(&passport.issue_year, &|v| number(v, Some(4), 2010, 2020))
+        && field(&passport.expiration_year, &|v| {
+            number(v, Some(4), 2020, 2030)
+        })
+        && field(
+            &passport.height,
+            &|v| match (v.strip_suffix("cm"), v.strip_suffix("in")) {
+                (Some(cm), _) => number(cm, None, 150, 193),
+                (_, Some(inches)) => number(inches, None, 59, 76),
+                _ => false,
+            },
+        )
+        && field(&passport.hair_color, &|v| {
+            v.len() == 7
+                && v.starts_with('#')
+                && v[1..].chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+        })
+        && field(&passport.eye_color, &|v| {
+            ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&v)
+        })
+        && field(&passport.passport_id, &|v| {
+            number(v, Some(9), 0, 999_999_999)
+        })
+}
+
+impl Reference for Day04 {
+    const REFERENCE_SIZE: usize = 200;
+
+    fn reference_part_one(passports: &Self::Input) -> Option<Answer> {
+        let complete = passports.iter().filter(|p| {
+            [
+                &p.birth_year,
+                &p.issue_year,
+                &p.expiration_year,
+                &p.height,
+                &p.hair_color,
+                &p.eye_color,
+                &p.passport_id,
+            ]
+            .iter()
+            .all(|field| field.is_some())
+        });
+
+        Some(complete.count().into())
+    }
+
+    fn reference_part_two(passports: &Self::Input) -> Option<Answer> {
+        Some(
+            passports
+                .iter()
+                .filter(|p| reference_valid(p))
+                .count()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+    const EXAMPLE_INVALID: &str = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+    const EXAMPLE_VALID: &str = "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+";
+
+    #[test]
+    fn example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_one(&input), Answer::Number(2));
+    }
+
+    #[test]
+    fn example_invalid() {
+        let input = Day04::parse(EXAMPLE_INVALID).unwrap();
+        assert_eq!(Day04::part_two(&input), Answer::Number(0));
+    }
+
+    #[test]
+    fn example_valid() {
+        let input = Day04::parse(EXAMPLE_VALID).unwrap();
+        assert_eq!(Day04::part_two(&input), Answer::Number(4));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day04::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day04::part_one(&input), Answer::Number(233));
+        assert_eq!(Day04::part_two(&input), Answer::Number(111));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day04::parse(&Day04::generate(&mut Rng::new(seed), 50)).unwrap();
+            assert_eq!(input.len(), 50);
+            assert!(count_valid(&input) <= count_complete(&input));
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day04>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day04>(EXAMPLE);
+    }
+}
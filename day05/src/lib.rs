@@ -0,0 +1,259 @@
+//! Solution to [Day 5: Binary Boarding](https://adventofcode.com/2020/day/5).
+
+/*
+--- Day 5: Binary Boarding ---
+
+You board your plane only to discover a new problem: you dropped your boarding pass! You aren't sure
+which seat is yours, and all of the flight attendants are busy with the flood of people that
+suddenly made it through passport control.
+
+You write a quick program to use your phone's camera to scan all of the nearby boarding passes (your
+    puzzle input); perhaps you can find your seat through process of elimination.
+
+Instead of zones or groups, this airline uses binary space partitioning to seat people. A seat might
+be specified like FBFBBFFRLR, where F means "front", B means "back", L means "left", and R means
+"right".
+
+The first 7 characters will either be F or B; these specify exactly one of the 128 rows on the plane
+(numbered 0 through 127). Each letter tells you which half of a region the given seat is in. Start
+with the whole list of rows; the first letter indicates whether the seat is in the front (0 through
+63) or the back (64 through 127). The next letter indicates which half of that region the seat is
+in, and so on until you're left with exactly one row.
+
+For example, consider just the first seven characters of FBFBBFFRLR:
+
+    Start by considering the whole range, rows 0 through 127.
+    F means to take the lower half, keeping rows 0 through 63.
+    B means to take the upper half, keeping rows 32 through 63.
+    F means to take the lower half, keeping rows 32 through 47.
+    B means to take the upper half, keeping rows 40 through 47.
+    B keeps rows 44 through 47.
+    F keeps rows 44 through 45.
+    The final F keeps the lower of the two, row 44.
+
+The last three characters will be either L or R; these specify exactly one of the 8 columns of seats
+on the plane (numbered 0 through 7). The same process as above proceeds again, this time with only
+three steps. L means to keep the lower half, while R means to keep the upper half.
+
+For example, consider just the last 3 characters of FBFBBFFRLR:
+
+    Start by considering the whole range, columns 0 through 7.
+    R means to take the upper half, keeping columns 4 through 7.
+    L means to take the lower half, keeping columns 4 through 5.
+    The final R keeps the upper of the two, column 5.
+
+So, decoding FBFBBFFRLR reveals that it is the seat at row 44, column 5.
+
+Every seat also has a unique seat ID: multiply the row by 8, then add the column. In this example,
+the seat has ID 44 * 8 + 5 = 357.
+
+Here are some other boarding passes:
+
+    BFFFBBFRRR: row 70, column 7, seat ID 567.
+    FFFBBBFRRR: row 14, column 7, seat ID 119.
+    BBFFBBFRLL: row 102, column 4, seat ID 820.
+
+As a sanity check, look through your list of boarding passes. What is the highest seat ID on a
+boarding pass?
+*/
+
+use common::input;
+use common::{Answer, Generate, ParseError, ParseResult, Reference, Rng, Solution};
+use log::debug;
+
+// RRRRRRR.CCC
+// 7 [F->B] to indicate row (0-127)
+// -> F = 0, B = 1
+// 3 [L->R] to indicate column (0-7)
+// -> L = 0, R = 1
+// total number of seats: 128 * 8 = 1024
+// seat id: row << 3 + column (= RRRRRRRCCC)
+/// Parses the boarding passes into seat IDs.
+pub fn parse_input(input: &str) -> ParseResult<Vec<i32>> {
+    let input = &*input::normalize(input);
+    let mut vec = Vec::new();
+    for s in input.lines() {
+        if s.len() != 10 {
+            return Err(ParseError::at(input, s, "a boarding pass of 10 characters"));
+        }
+        let mut value = 0;
+        for (i, c) in s.char_indices() {
+            let token = &s[i..i + c.len_utf8()];
+            value <<= 1;
+            match (i < 7, c) {
+                (true, 'B') | (false, 'R') => value += 1,
+                (true, 'F') | (false, 'L') => (),
+                (true, _) => return Err(ParseError::at(input, token, "`F` or `B`")),
+                (false, _) => return Err(ParseError::at(input, token, "`L` or `R`")),
+            }
+        }
+        vec.push(value);
+    }
+
+    Ok(vec)
+}
+
+/// The highest seat ID.
+pub fn part_one(seat_ids: &[i32]) -> i32 {
+    let mut highest_id = 0;
+    for &value in seat_ids {
+        if value > highest_id {
+            highest_id = value;
+        }
+    }
+
+    highest_id
+}
+
+/*
+--- Part Two ---
+
+Ding! The "fasten seat belt" signs have turned on. Time to find your seat.
+
+It's a completely full flight, so your seat should be the only missing boarding pass in your list.
+However, there's a catch: some of the seats at the very front and back of the plane don't exist on
+this aircraft, so they'll be missing from your list as well.
+
+Your seat wasn't at the very front or back, though; the seats with IDs +1 and -1 from yours will be
+in your list.
+
+What is the ID of your seat?
+*/
+
+/// Your seat: the missing ID whose neighbours are both taken, if there is one.
+pub fn part_two(seat_ids: &[i32]) -> Option<i32> {
+    // Search space is small enough that we can put
+    // everything in it (1024)
+    let mut all = vec![];
+    for i in 0..1024 {
+        all.push(i);
+    }
+
+    for &value in seat_ids {
+        all[value as usize] = -1;
+    }
+
+    // Check which ones are left
+    let mut prev = 0;
+    for val in all {
+        if val != -1 {
+            if val - prev > 1 {
+                // Translate back
+                let col = val & 7;
+                let row = val >> 3;
+                debug!("My seat: Id = {}, Row = {}, Column {}", val, row, col);
+                return Some(val);
+            }
+            prev = val;
+        }
+    }
+
+    None
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Binary Boarding";
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day05 {
+    const SIZE: usize = 800;
+    // A plane has 1024 seats, and at least the first one and ours are not on a boarding pass
+    const MAX_SIZE: usize = 1021;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.clamp(2, Self::MAX_SIZE) as i64;
+        let start = rng.range(1..=1023 - size);
+        let mine = rng.range(start + 1..=start + size - 1);
+        let mut seat_ids: Vec<i64> = (start..=start + size).filter(|&id| id != mine).collect();
+        rng.shuffle(&mut seat_ids);
+
+        let mut passes = String::new();
+        for id in seat_ids {
+            for bit in (0..10).rev() {
+                let set = (id >> bit) & 1 == 1;
+                passes.push(match (bit >= 3, set) {
+                    (true, false) => 'F',
+                    (true, true) => 'B',
+                    (false, false) => 'L',
+                    (false, true) => 'R',
+                });
+            }
+            passes.push('\n');
+        }
+
+        passes
+    }
+}
+
+impl Reference for Day05 {
+    const REFERENCE_SIZE: usize = 1000;
+
+    fn reference_part_one(seat_ids: &Self::Input) -> Option<Answer> {
+        Some(seat_ids.iter().max().copied().unwrap_or(0).into())
+    }
+
+    // The missing seat with both of its neighbours taken.
+    fn reference_part_two(seat_ids: &Self::Input) -> Option<Answer> {
+        let seat = (1..1023).find(|id| {
+            !seat_ids.contains(id) && seat_ids.contains(&(id - 1)) && seat_ids.contains(&(id + 1))
+        });
+
+        Some(seat.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day05::parse("BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n").unwrap();
+        assert_eq!(input, vec![567, 119, 820]);
+        assert_eq!(Day05::part_one(&input), Answer::Number(820));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day05::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day05::part_one(&input), Answer::Number(892));
+        assert_eq!(Day05::part_two(&input), Answer::Number(625));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let mut rng = Rng::new(seed);
+            let input = Day05::parse(&Day05::generate(&mut rng, 50)).unwrap();
+            assert_eq!(input.len(), 50);
+            assert!(Day05::part_two(&input) != Answer::Unsolved);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day05>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day05>("BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n");
+    }
+}
@@ -0,0 +1,321 @@
+//! Solution to [Day 6: Custom Customs](https://adventofcode.com/2020/day/6).
+
+use common::input;
+use common::{Answer, Generate, ParseError, ParseResult, Reference, Rng, Solution};
+use log::trace;
+use std::collections::HashSet;
+
+/*
+--- Day 6: Custom Customs ---
+
+As your flight approaches the regional airport where you'll switch to a much larger plane, customs
+declaration forms are distributed to the passengers.
+
+The form asks a series of 26 yes-or-no questions marked a through z. All you need to do is identify
+the questions for which anyone in your group answers "yes". Since your group is just you, this
+doesn't take very long.
+
+However, the person sitting next to you seems to be experiencing a language barrier and asks if you
+can help. For each of the people in their group, you write down the questions for which they answer
+"yes", one per line. For example:
+
+abcx
+abcy
+abcz
+
+In this group, there are 6 questions to which anyone answered "yes": a, b, c, x, y, and z.
+(Duplicate answers to the same question don't count extra; each question counts at most once.)
+
+Another group asks for your help, then another, and eventually you've collected answers from every
+group on the plane (your puzzle input). Each group's answers are separated by a blank line, and
+within each group, each person's answers are on a single line. For example:
+
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+
+This list represents answers from five groups:
+
+The first group contains one person who answered "yes" to 3 questions: a, b, and c.
+The second group contains three people; combined, they answered "yes" to 3 questions: a, b, and c.
+The third group contains two people; combined, they answered "yes" to 3 questions: a, b, and c.
+The fourth group contains four people; combined, they answered "yes" to only 1 question, a.
+The last group contains one person who answered "yes" to only 1 question, b.
+
+In this example, the sum of these counts is 3 + 3 + 3 + 1 + 1 = 11.
+
+For each group, count the number of questions to which anyone answered "yes". What is the sum of
+those counts?
+
+*/
+
+// Groups (paragraphs) of people (lines) of answers (characters)
+// Get union set of answers
+/// Parses the answers per group: one string of questions answered "yes" per person.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Vec<String>>> {
+    let input = &*input::normalize(input);
+    let mut groups = Vec::new();
+    for group in input.split("\n\n") {
+        let mut people = Vec::new();
+        // Extra blank lines between groups are no one
+        for person in group.lines().filter(|person| !person.is_empty()) {
+            if let Some((i, c)) = person.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+                let token = &person[i..i + c.len_utf8()];
+                return Err(ParseError::at(input, token, "a question from `a` to `z`"));
+            }
+            people.push(person.to_owned());
+        }
+        if !people.is_empty() {
+            groups.push(people);
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Sum over all groups of the number of questions anyone in the group answered "yes" to.
+pub fn part_one(groups: &[Vec<String>]) -> usize {
+    let mut sum_counts = 0;
+
+    // Let's see if we can be a little "smart"
+    // with list mappings and such.
+    // Less lines, but more expensive.
+    for group in groups {
+        let mut answers = HashSet::new();
+        for people in group {
+            for answer in people.chars() {
+                answers.insert(answer);
+            }
+        }
+        trace!("Count : {} ", answers.len());
+        sum_counts += answers.len();
+    }
+
+    sum_counts
+}
+
+/*
+--- Part Two ---
+
+As you finish the last group's customs declaration, you notice that you misread one word in the
+instructions:
+
+You don't need to identify the questions to which anyone answered "yes"; you need to identify the
+questions to which everyone answered "yes"!
+
+Using the same example as above:
+
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+
+This list represents answers from five groups:
+
+In the first group, everyone (all 1 person) answered "yes" to 3 questions: a, b, and c.
+In the second group, there is no question to which everyone answered "yes".
+In the third group, everyone answered yes to only 1 question, a. Since some people did not answer
+    "yes" to b or c, they don't count.
+In the fourth group, everyone answered yes to only 1 question, a.
+In the fifth group, everyone (all 1 person) answered "yes" to 1 question, b.
+
+In this example, the sum of these counts is 3 + 0 + 1 + 1 + 1 = 6.
+
+For each group, count the number of questions to which everyone answered "yes". What is the sum of
+those counts?
+*/
+
+// Intersection instead of union.
+/// Sum over all groups of the number of questions everyone in the group answered "yes" to.
+pub fn part_two(groups: &[Vec<String>]) -> usize {
+    let mut sum_counts = 0;
+
+    let mut base = HashSet::with_capacity(26);
+    // Note the = here to do an inclusive range.
+    // Had a bug here earlier where we only used 25 characters.
+    for x in 'a'..='z' {
+        base.insert(x);
+    }
+    trace!("base: {} - {:?}", base.len(), base);
+    for group in groups {
+        let mut intersection = base.clone();
+        for people in group {
+            let mut answers = HashSet::new();
+            for answer in people.chars() {
+                answers.insert(answer);
+            }
+            intersection = intersection.intersection(&answers).cloned().collect();
+        }
+        trace!("Intersection: {} - {:?}", intersection.len(), intersection);
+        sum_counts += intersection.len();
+    }
+
+    sum_counts
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Custom Customs";
+
+    type Input = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day06 {
+    const SIZE: usize = 490;
+
+    // `size` groups of one to five people, who each answered "yes" to at least one question.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut groups = Vec::new();
+        for _ in 0..size {
+            let mut people = Vec::new();
+            for _ in 0..rng.range(1..=5) {
+                let mut person: String = ('a'..='z').filter(|_| rng.chance(0.3)).collect();
+                if person.is_empty() {
+                    person.push(*rng.choose(&['a', 'm', 'z']));
+                }
+                people.push(person);
+            }
+            groups.push(people.join("\n"));
+        }
+
+        groups.join("\n\n") + "\n"
+    }
+}
+
+// Goes through the questions one at a time.
+impl Reference for Day06 {
+    const REFERENCE_SIZE: usize = 200;
+
+    fn reference_part_one(groups: &Self::Input) -> Option<Answer> {
+        let sum: usize = groups
+            .iter()
+            .map(|group| {
+                ('a'..='z')
+                    .filter(|&question| group.iter().any(|person| person.contains(question)))
+                    .count()
+            })
+            .sum();
+
+        Some(sum.into())
+    }
+
+    fn reference_part_two(groups: &Self::Input) -> Option<Answer> {
+        let sum: usize = groups
+            .iter()
+            .map(|group| {
+                ('a'..='z')
+                    .filter(|&question| group.iter().all(|person| person.contains(question)))
+                    .count()
+            })
+            .sum();
+
+        Some(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    #[test]
+    fn example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part_one(&input), Answer::Number(11));
+        assert_eq!(Day06::part_two(&input), Answer::Number(6));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day06::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day06::part_one(&input), Answer::Number(6911));
+        assert_eq!(Day06::part_two(&input), Answer::Number(3473));
+    }
+
+    #[test]
+    fn generated() {
+        let mut rng = Rng::new(0);
+        let input = Day06::parse(&Day06::generate(&mut rng, 50)).unwrap();
+        assert_eq!(input.len(), 50);
+        assert!(part_two(&input) <= part_one(&input));
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day06>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day06>(EXAMPLE);
+    }
+
+    #[test]
+    fn extra_blank_lines() {
+        let input = Day06::parse("ab\n\n\nab\n").unwrap();
+        assert_eq!(input, [["ab"], ["ab"]]);
+        assert_eq!(Day06::part_two(&input), Answer::Number(4));
+
+        let input = Day06::parse("\n\nabc\n\n\n\na\nb\n").unwrap();
+        assert_eq!(Day06::part_one(&input), Answer::Number(5));
+        assert_eq!(Day06::part_two(&input), Answer::Number(3));
+
+        let input = Day06::parse("").unwrap();
+        assert!(input.is_empty());
+        assert_eq!(Day06::part_one(&input), Answer::Number(0));
+        assert_eq!(Day06::part_two(&input), Answer::Number(0));
+    }
+}
@@ -0,0 +1,499 @@
+//! Solution to [Day 7: Handy Haversacks](https://adventofcode.com/2020/day/7).
+
+use common::input;
+use common::parse::{self, ParseError, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::trace;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/*
+--- Day 7: Handy Haversacks ---
+
+You land at the regional airport in time for your next flight. In fact, it looks like you'll even
+have time to grab some food: all flights are currently delayed due to issues in luggage processing.
+
+Due to recent aviation regulations, many rules (your puzzle input) are being enforced about bags and
+their contents; bags must be color-coded and must contain specific quantities of other color-coded
+bags. Apparently, nobody responsible for these regulations considered how long they would take to
+enforce!
+
+For example, consider the following rules:
+
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+
+These rules specify the required contents for 9 bag types. In this example, every faded blue bag is
+empty, every vibrant plum bag contains 11 bags (5 faded blue and 6 dotted black), and so on.
+
+You have a shiny gold bag. If you wanted to carry it in at least one other bag, how many different
+bag colors would be valid for the outermost bag? (In other words: how many colors can, eventually,
+contain at least one shiny gold bag?)
+
+In the above rules, the following options would be available to you:
+
+    A bright white bag, which can hold your shiny gold bag directly.
+    A muted yellow bag, which can hold your shiny gold bag directly, plus some other bags.
+    A dark orange bag, which can hold bright white and muted yellow bags, either of which could then
+    hold your shiny gold bag.
+    A light red bag, which can hold bright white and muted yellow bags, either of which could then
+    hold your shiny gold bag.
+
+So, in this example, the number of bag colors that can eventually contain at least one shiny gold
+bag is 4.
+
+How many bag colors can eventually contain at least one shiny gold bag? (The list of rules is quite
+long; make sure you get all of it.)
+*/
+
+fn expand(map: &HashMap<String, HashSet<String>>, set: &HashSet<String>) -> HashSet<String> {
+    let mut result = set.clone();
+    for bag in set {
+        if let Some(list) = map.get(bag) {
+            result.extend(expand(map, list));
+        }
+    }
+
+    result
+}
+
+/// A single line of the rules: the container bag and the bags it must directly hold.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub container: String,
+    pub contents: Vec<(usize, String)>,
+}
+
+// Walks the bags held by `bag`, in depth-first order. `state` is 1 for bags on the current path
+// and 2 for bags already done. Returns the index of the rule that holds a bag on the path, and
+// that bag.
+fn find_cycle<'a>(
+    rules: &'a [Rule],
+    containers: &HashMap<&str, Vec<usize>>,
+    state: &mut HashMap<&'a str, u8>,
+    bag: &'a str,
+) -> Option<(usize, &'a str)> {
+    state.insert(bag, 1);
+    for &index in containers.get(bag).into_iter().flatten() {
+        for (_count, contents) in &rules[index].contents {
+            match state.get(contents.as_str()) {
+                Some(1) => return Some((index, contents)),
+                Some(_) => (),
+                None => {
+                    if let Some(cycle) = find_cycle(rules, containers, state, contents) {
+                        return Some(cycle);
+                    }
+                }
+            }
+        }
+    }
+    state.insert(bag, 2);
+
+    None
+}
+
+/// Parses the rules. Bags that hold no other bags get no rule. Rules under which a bag ends up
+/// holding itself are rejected, as no number of bags would do.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Rule>> {
+    let input = &*input::normalize(input);
+    let mut rules = Vec::new();
+    let mut lines = Vec::new();
+    let g = input.lines().filter(|p| !p.ends_with("no other bags."));
+    for bag in g {
+        let mut iter = bag
+            .split(' ')
+            .map(|p| p.trim_end_matches(&[',', '.'][..]))
+            .filter(|p| *p != "bags" && *p != "bag" && *p != "contain")
+            .peekable();
+        let container = parse::next(input, bag, &mut iter, "a bag color")?.to_owned()
+            + " "
+            + parse::next(input, bag, &mut iter, "a bag color")?;
+        trace!("{:?}", container);
+        let mut contents = Vec::new();
+        while iter.peek().is_some() {
+            let count = parse::next(input, bag, &mut iter, "a number of bags")?;
+            let count = parse::value(input, count, "a number of bags")?;
+            let bag = parse::next(input, bag, &mut iter, "a bag color")?.to_owned()
+                + " "
+                + parse::next(input, bag, &mut iter, "a bag color")?;
+            contents.push((count, bag));
+        }
+        rules.push(Rule {
+            container,
+            contents,
+        });
+        lines.push(bag);
+    }
+
+    let mut containers: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, rule) in rules.iter().enumerate() {
+        containers.entry(&rule.container).or_default().push(index);
+    }
+    let mut state = HashMap::new();
+    for rule in &rules {
+        if state.contains_key(rule.container.as_str()) {
+            continue;
+        }
+        if let Some((index, bag)) = find_cycle(&rules, &containers, &mut state, &rule.container) {
+            return Err(ParseError::at(
+                input,
+                lines[index],
+                format!("a rule that does not lead back to the {} bag", bag),
+            ));
+        }
+    }
+
+    Ok(rules)
+}
+
+/// Number of bag colors that can eventually hold a shiny gold bag.
+pub fn part_one(rules: &[Rule]) -> usize {
+    let mut reverse_search = HashMap::new();
+    for rule in rules {
+        for (_count, contents) in &rule.contents {
+            let reverse_set = reverse_search
+                .entry(contents.clone())
+                .or_insert(HashSet::new());
+            reverse_set.insert(rule.container.clone());
+        }
+    }
+
+    let mut result = 0;
+    if let Some(a) = reverse_search.get("shiny gold") {
+        result = expand(&reverse_search, a).len();
+    }
+
+    result
+}
+
+/*
+--- Part Two ---
+
+It's getting pretty expensive to fly these days - not because of ticket prices, but because of the
+ridiculous number of bags you need to buy!
+
+Consider again your shiny gold bag and the rules from the above example:
+
+    faded blue bags contain 0 other bags.
+    dotted black bags contain 0 other bags.
+    vibrant plum bags contain 11 other bags: 5 faded blue bags and 6 dotted black bags.
+    dark olive bags contain 7 other bags: 3 faded blue bags and 4 dotted black bags.
+
+So, a single shiny gold bag must contain 1 dark olive bag (and the 7 bags within it) plus 2 vibrant
+plum bags (and the 11 bags within each of those): 1 + 1*7 + 2 + 2*11 = 32 bags!
+
+Of course, the actual rules have a small chance of going several levels deeper than this example; be
+sure to count all of the bags, even if the nesting becomes topologically impractical!
+
+Here's another example:
+
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+
+In this example, a single shiny gold bag must contain 126 other bags.
+
+How many individual bags are required inside your single shiny gold bag?
+*/
+fn count_bags(
+    map: &HashMap<String, HashSet<(String, usize)>>,
+    set: &HashSet<(String, usize)>,
+) -> usize {
+    let mut result = 0;
+    for tuple in set {
+        result += tuple.1;
+        if let Some(list) = map.get(&tuple.0) {
+            result += tuple.1 * count_bags(map, list);
+        }
+    }
+
+    result
+}
+
+/// Number of bags a single shiny gold bag must hold.
+pub fn part_two(rules: &[Rule]) -> usize {
+    let mut reverse_search = HashMap::new();
+    for rule in rules {
+        for (count, contents) in &rule.contents {
+            let reverse_set = reverse_search
+                .entry(rule.container.clone())
+                .or_insert(HashSet::new());
+            reverse_set.insert((contents.clone(), *count));
+        }
+    }
+
+    let mut result = 0;
+    if let Some(a) = reverse_search.get("shiny gold") {
+        result = count_bags(&reverse_search, a);
+    }
+
+    result
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Handy Haversacks";
+
+    type Input = Vec<Rule>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+const ADJECTIVES: [&str; 30] = [
+    "bold", "bright", "clear", "dark", "deep", "dim", "dotted", "drab", "dull", "dusty", "faded",
+    "faint", "glossy", "hazy", "light", "matte", "mirrored", "muted", "pale", "plaid", "posh",
+    "rough", "shiny", "soft", "striped", "vibrant", "vivid", "wavy", "wet", "worn",
+];
+const COLORS: [&str; 30] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "violet",
+    "white",
+];
+
+impl Generate for Day07 {
+    const SIZE: usize = 594;
+
+    // The bags are put in levels, and a bag only holds bags from lower levels, so that no bag
+    // ends up inside itself. Shiny gold sits in the middle, so that it both holds bags and is
+    // held by them, and the levels are few enough for the count of bags in it to stay small.
+    // Past the 900 real color names, more are made up by numbering the adjectives.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const LEVELS: usize = 7;
+        let named = |round: usize| {
+            ADJECTIVES.iter().flat_map(move |adjective| {
+                COLORS.iter().map(move |color| match round {
+                    1 => format!("{} {}", adjective, color),
+                    _ => format!("{}{} {}", adjective, round, color),
+                })
+            })
+        };
+        let mut names: Vec<String> = named(1).filter(|name| name != "shiny gold").collect();
+        rng.shuffle(&mut names);
+        let size = size.max(LEVELS + 1);
+        let mut round = 1;
+        while names.len() < size - 1 {
+            round += 1;
+            names.extend(named(round));
+        }
+        names.truncate(size - 1);
+        names.push("shiny gold".to_owned());
+        let gold = names.len() - 1;
+
+        let level = |i: usize| if i == gold { LEVELS / 2 } else { i % LEVELS };
+        // The bags on levels below each level
+        let below: Vec<Vec<usize>> = (0..LEVELS)
+            .map(|l| (0..names.len()).filter(|&j| level(j) < l).collect())
+            .collect();
+        let mut rules = Vec::new();
+        for (i, name) in names.iter().enumerate() {
+            let below = &below[level(i)];
+            let mut contents = Vec::new();
+            // Make sure at least one bag holds shiny gold
+            if i == LEVELS - 1 {
+                contents.push((rng.range(1..=5), &names[gold]));
+            }
+            if !below.is_empty() {
+                for _ in 0..rng.range(0..=4) {
+                    let bag = &names[*rng.choose(below)];
+                    if contents.iter().all(|(_, b)| *b != bag) {
+                        contents.push((rng.range(1..=5), bag));
+                    }
+                }
+            }
+            let contents: Vec<String> = contents
+                .iter()
+                .map(|(count, bag)| {
+                    let plural = if *count == 1 { "" } else { "s" };
+                    format!("{} {} bag{}", count, bag, plural)
+                })
+                .collect();
+            if contents.is_empty() {
+                rules.push(format!("{} bags contain no other bags.", name));
+            } else {
+                rules.push(format!("{} bags contain {}.", name, contents.join(", ")));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        rules.join("\n") + "\n"
+    }
+}
+
+// Whether `bag` ends up holding shiny gold, following the rules one bag at a time.
+fn reference_holds_gold(rules: &[Rule], bag: &str) -> bool {
+    rules
+        .iter()
+        .filter(|rule| rule.container == bag)
+        .flat_map(|rule| &rule.contents)
+        .any(|(_, inner)| inner == "shiny gold" || reference_holds_gold(rules, inner))
+}
+
+// Number of bags inside `bag`.
+fn reference_inside(rules: &[Rule], bag: &str) -> usize {
+    rules
+        .iter()
+        .filter(|rule| rule.container == bag)
+        .flat_map(|rule| &rule.contents)
+        .map(|(count, inner)| count * (1 + reference_inside(rules, inner)))
+        .sum()
+}
+
+impl Reference for Day07 {
+    const REFERENCE_SIZE: usize = 100;
+
+    fn reference_part_one(rules: &Self::Input) -> Option<Answer> {
+        let mut bags: Vec<&str> = rules.iter().map(|rule| rule.container.as_str()).collect();
+        bags.sort_unstable();
+        bags.dedup();
+        let count = bags
+            .iter()
+            .filter(|bag| reference_holds_gold(rules, bag))
+            .count();
+
+        Some(count.into())
+    }
+
+    fn reference_part_two(rules: &Self::Input) -> Option<Answer> {
+        Some(reference_inside(rules, "shiny gold").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+    const EXAMPLE_DEEP: &str = "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+";
+
+    #[test]
+    fn example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_one(&input), Answer::Number(4));
+        assert_eq!(Day07::part_two(&input), Answer::Number(32));
+    }
+
+    #[test]
+    fn example_deep() {
+        let input = Day07::parse(EXAMPLE_DEEP).unwrap();
+        assert_eq!(Day07::part_two(&input), Answer::Number(126));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day07::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day07::part_one(&input), Answer::Number(169));
+        assert_eq!(Day07::part_two(&input), Answer::Number(82372));
+    }
+
+    #[test]
+    fn cycle() {
+        let rules = "\
+shiny gold bags contain 1 dark red bag.
+dark red bags contain 1 shiny gold bag.
+";
+        let error = Day07::parse(rules).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(
+            error.expected,
+            "a rule that does not lead back to the shiny gold bag"
+        );
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let text = Day07::generate(&mut Rng::new(seed), 100);
+            assert_eq!(text.lines().count(), 100);
+            let input = Day07::parse(&text).unwrap();
+            assert!(input.iter().all(|rule| !rule.contents.is_empty()));
+            assert!(part_one(&input) > 0);
+            part_two(&input);
+        }
+    }
+
+    #[test]
+    fn generated_past_color_names() {
+        let input = Day07::parse(&Day07::generate(&mut Rng::new(0), 2000)).unwrap();
+        let containers: HashSet<&str> = input.iter().map(|rule| rule.container.as_str()).collect();
+        assert!(containers.len() > 900);
+        assert!(part_one(&input) > 0);
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day07>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day07>(EXAMPLE);
+    }
+}
@@ -0,0 +1,394 @@
+//! Solution to [Day 8: Handheld Halting](https://adventofcode.com/2020/day/8).
+
+/*
+--- Day 8: Handheld Halting ---
+
+Your flight to the major airline hub reaches cruising altitude without incident. While you consider
+checking the in-flight menu for one of those drinks that come with a little umbrella, you are
+interrupted by the kid sitting next to you.
+
+Their handheld game console won't turn on! They ask if you can take a look.
+
+You narrow the problem down to a strange infinite loop in the boot code (your puzzle input) of the
+device. You should be able to fix it, but first you need to be able to run the code in isolation.
+
+The boot code is represented as a text file with one instruction per line of text. Each instruction
+consists of an operation (acc, jmp, or nop) and an argument (a signed number like +4 or -20).
+
+    acc increases or decreases a single global value called the accumulator by the value given in
+        the argument. For example, acc +7 would increase the accumulator by 7. The accumulator
+        starts at 0. After an acc instruction, the instruction immediately below it is executed
+        next.
+    jmp jumps to a new instruction relative to itself. The next instruction to execute is found
+        using the argument as an offset from the jmp instruction; for example, jmp +2 would skip the
+        next instruction, jmp +1 would continue to the instruction immediately below it, and jmp -20
+        would cause the instruction 20 lines above to be executed next.
+    nop stands for No OPeration - it does nothing. The instruction immediately below it is executed
+        next.
+
+For example, consider the following program:
+
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+
+These instructions are visited in this order:
+
+nop +0  | 1
+acc +1  | 2, 8(!)
+jmp +4  | 3
+acc +3  | 6
+jmp -3  | 7
+acc -99 |
+acc +1  | 4
+jmp -4  | 5
+acc +6  |
+
+First, the nop +0 does nothing. Then, the accumulator is increased from 0 to 1 (acc +1) and jmp +4
+sets the next instruction to the other acc +1 near the bottom. After it increases the accumulator
+from 1 to 2, jmp -4 executes, setting the next instruction to the only acc +3. It sets the
+accumulator to 5, and jmp -3 causes the program to continue back at the first acc +1.
+
+This is an infinite loop: with this sequence of jumps, the program will run forever. The moment the
+program tries to run any instruction a second time, you know it will never terminate.
+
+Immediately before the program would run an instruction a second time, the value in the accumulator
+is 5.
+
+Run your copy of the boot code. Immediately before any instruction is executed a second time, what
+value is in the accumulator?
+*/
+
+use common::input;
+use common::parse::{self, ParseError, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::{debug, trace};
+use std::convert::TryFrom;
+
+/// What an instruction does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+/// One line of the boot code: an operation with its signed argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub operation: Operation,
+    pub argument: i32,
+}
+
+/// Parses the boot code, one instruction per line.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Instruction>> {
+    let input = &*input::normalize(input);
+    let mut program = Vec::new();
+    for line in input.lines() {
+        let mut tokens = line.split(' ');
+        let keyword = parse::next(input, line, &mut tokens, "an operation")?;
+        let operation = match keyword {
+            "acc" => Operation::Acc,
+            "jmp" => Operation::Jmp,
+            "nop" => Operation::Nop,
+            _ => return Err(ParseError::at(input, keyword, "`acc`, `jmp` or `nop`")),
+        };
+        let argument = parse::next(input, line, &mut tokens, "a signed number")?;
+        // i32 parsing accepts a leading + as well
+        let argument = parse::value(input, argument, "a signed number")?;
+        program.push(Instruction {
+            operation,
+            argument,
+        });
+    }
+
+    Ok(program)
+}
+
+/// Runs a single instruction, updating `accumulator`. Returns the offset to the next
+/// instruction.
+pub fn execute_instruction(instruction: &Instruction, accumulator: &mut i32) -> i32 {
+    match instruction.operation {
+        Operation::Acc => {
+            *accumulator += instruction.argument;
+            1
+        }
+        Operation::Jmp => instruction.argument,
+        Operation::Nop => 1,
+    }
+}
+
+// Accumulator is a signed integer, starts at zero.
+// jmp instructions are relative
+/// Value of the accumulator right before any instruction would run a second time.
+pub fn part_one(a: &[Instruction]) -> i32 {
+    let mut history = vec![];
+    let mut instruction_index = 0;
+    let mut accumulator = 0;
+    while !history.contains(&instruction_index) {
+        history.push(instruction_index);
+        // Jumping out of the program ends it as well
+        let instruction = match usize::try_from(instruction_index)
+            .ok()
+            .and_then(|i| a.get(i))
+        {
+            Some(instruction) => instruction,
+            None => break,
+        };
+        trace!("{}: {:?}", instruction_index + 1, instruction);
+        instruction_index += execute_instruction(instruction, &mut accumulator);
+    }
+
+    accumulator
+}
+
+/*
+--- Part Two ---
+
+After some careful analysis, you believe that exactly one instruction is corrupted.
+
+Somewhere in the program, either a jmp is supposed to be a nop, or a nop is supposed to be a jmp.
+(No acc instructions were harmed in the corruption of this boot code.)
+
+The program is supposed to terminate by attempting to execute an instruction immediately after the
+last instruction in the file. By changing exactly one jmp or nop, you can repair the boot code and
+make it terminate correctly.
+
+For example, consider the same program from above:
+
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+
+If you change the first instruction from nop +0 to jmp +0, it would create a single-instruction
+infinite loop, never leaving that instruction. If you change almost any of the jmp instructions, the
+program will still eventually find another jmp instruction and loop forever.
+
+However, if you change the second-to-last instruction (from jmp -4 to nop -4), the program
+terminates! The instructions are visited in this order:
+
+nop +0  | 1
+acc +1  | 2
+jmp +4  | 3
+acc +3  |
+jmp -3  |
+acc -99 |
+acc +1  | 4
+nop -4  | 5
+acc +6  | 6
+
+After the last instruction (acc +6), the program terminates by attempting to run the instruction
+below the last instruction in the file. With this change, after the program terminates, the
+accumulator contains the value 8 (acc +1, acc +1, acc +6).
+
+Fix the program so that it terminates normally by changing exactly one jmp (to nop) or nop (to jmp).
+What is the value of the accumulator after the program terminates?
+*/
+
+/// Value of the accumulator after the program terminates, once the one corrupted `jmp` or
+/// `nop` is fixed.
+pub fn part_two(program: &[Instruction]) -> i32 {
+    // Work on a copy, so we can patch one instruction at a time
+    let mut lines = program.to_vec();
+
+    for i in 0..lines.len() {
+        let original = lines[i];
+
+        match lines[i].operation {
+            Operation::Jmp => lines[i].operation = Operation::Nop,
+            Operation::Nop => lines[i].operation = Operation::Jmp,
+            Operation::Acc => (),
+        }
+
+        let mut instruction_index = 0;
+        let mut accumulator = 0;
+        let mut history = vec![];
+        while !history.contains(&instruction_index) {
+            history.push(instruction_index);
+            // Jumping anywhere else but right after the last instruction is no fix either
+            let instruction = match usize::try_from(instruction_index)
+                .ok()
+                .and_then(|i| lines.get(i))
+            {
+                Some(instruction) => instruction,
+                None => break,
+            };
+            trace!("{}: {:?}", instruction_index + 1, instruction);
+            instruction_index += execute_instruction(instruction, &mut accumulator);
+
+            if instruction_index as usize == lines.len() {
+                debug!("Fixed line {}", i);
+                return accumulator;
+            }
+        }
+
+        lines[i] = original;
+    }
+
+    0
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Handheld Halting";
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day08 {
+    const SIZE: usize = 636;
+
+    // Apart from the one corrupted `jmp`, that jumps back and makes the program loop, everything
+    // goes forward. The jumps before it never jump past it, and those after it never jump past
+    // the end, so that only fixing that `jmp` makes the program terminate.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2) as i64;
+        let corrupted = rng.range(size / 2..=size - 1);
+        let mut lines = Vec::new();
+        for i in 0..size {
+            let limit = if i < corrupted { corrupted } else { size };
+            let line = if i == corrupted {
+                format!("jmp {:+}", rng.range(0..=i) - i)
+            } else if rng.chance(0.5) {
+                format!("acc {:+}", rng.range(-50..=50))
+            } else if rng.chance(0.5) {
+                let distance = rng.range(1..=(limit - i).clamp(1, 20));
+                format!("jmp {:+}", distance)
+            } else if i < corrupted {
+                // Changed into a `jmp` this must not end up past the corrupted instruction
+                format!("nop {:+}", rng.range(0..=(limit - i).min(20)))
+            } else {
+                format!("nop {:+}", rng.range(-50..=50))
+            };
+            lines.push(line);
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+// Runs the program until it repeats an instruction or jumps out of it. Returns the accumulator,
+// and whether the program ended by going to the instruction right after the last one.
+fn reference_run(program: &[Instruction]) -> (i32, bool) {
+    let mut visited = vec![false; program.len()];
+    let mut index: i64 = 0;
+    let mut accumulator = 0;
+    while 0 <= index && (index as usize) < program.len() && !visited[index as usize] {
+        visited[index as usize] = true;
+        let instruction = program[index as usize];
+        match instruction.operation {
+            Operation::Acc => {
+                accumulator += instruction.argument;
+                index += 1;
+            }
+            Operation::Jmp => index += i64::from(instruction.argument),
+            Operation::Nop => index += 1,
+        }
+    }
+
+    (accumulator, index == program.len() as i64)
+}
+
+impl Reference for Day08 {
+    const REFERENCE_SIZE: usize = 200;
+
+    fn reference_part_one(program: &Self::Input) -> Option<Answer> {
+        Some(reference_run(program).0.into())
+    }
+
+    fn reference_part_two(program: &Self::Input) -> Option<Answer> {
+        for i in 0..program.len() {
+            let mut patched = program.clone();
+            patched[i].operation = match program[i].operation {
+                Operation::Jmp => Operation::Nop,
+                Operation::Nop => Operation::Jmp,
+                Operation::Acc => continue,
+            };
+            if let (accumulator, true) = reference_run(&patched) {
+                return Some(accumulator.into());
+            }
+        }
+
+        Some(0.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+    #[test]
+    fn example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part_one(&input), Answer::Number(5));
+        assert_eq!(Day08::part_two(&input), Answer::Number(8));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day08::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day08::part_one(&input), Answer::Number(1501));
+        assert_eq!(Day08::part_two(&input), Answer::Number(509));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day08::parse(&Day08::generate(&mut Rng::new(seed), 100)).unwrap();
+            assert_eq!(input.len(), 100);
+            let backward = input
+                .iter()
+                .filter(|i| i.operation == Operation::Jmp && i.argument <= 0)
+                .count();
+            assert_eq!(backward, 1);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day08>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day08>(EXAMPLE);
+    }
+}
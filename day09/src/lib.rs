@@ -0,0 +1,343 @@
+//! Solution to [Day 9: Encoding Error](https://adventofcode.com/2020/day/9).
+
+use common::input;
+use common::parse::{self, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::trace;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+/*
+--- Day 9: Encoding Error ---
+
+With your neighbor happily enjoying their video game, you turn your attention to an open data port
+on the little screen in the seat in front of you.
+
+Though the port is non-standard, you manage to connect it to your computer through the clever use of
+several paperclips. Upon connection, the port outputs a series of numbers (your puzzle input).
+
+The data appears to be encrypted with the eXchange-Masking Addition System (XMAS) which,
+conveniently for you, is an old cypher with an important weakness.
+
+XMAS starts by transmitting a preamble of 25 numbers. After that, each number you receive should be
+the sum of any two of the 25 immediately previous numbers. The two numbers will have different
+values, and there might be more than one such pair.
+
+For example, suppose your preamble consists of the numbers 1 through 25 in a random order. To be
+valid, the next number must be the sum of two of those numbers:
+
+    26 would be a valid next number, as it could be 1 plus 25 (or many other pairs, like 2 and 24).
+    49 would be a valid next number, as it is the sum of 24 and 25.
+    100 would not be valid; no two of the previous 25 numbers sum to 100.
+    50 would also not be valid; although 25 appears in the previous 25 numbers, the two numbers in
+    the pair must be different.
+
+Suppose the 26th number is 45, and the first number (no longer an option, as it is more than 25
+    numbers ago) was 20. Now, for the next number to be valid, there needs to be some pair of
+    numbers among 1-19, 21-25, or 45 that add up to it:
+
+    26 would still be a valid next number, as 1 and 25 are still within the previous 25 numbers.
+    65 would not be valid, as no two of the available numbers sum to it.
+    64 and 66 would both be valid, as they are the result of 19+45 and 21+45 respectively.
+
+Here is a larger example which only considers the previous 5 numbers (and has a preamble of length
+5):
+
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+
+In this example, after the 5-number preamble, almost every number is the sum of two of the previous
+5 numbers; the only number that does not follow this rule is 127.
+
+The first step of attacking the weakness in the XMAS data is to find the first number in the list
+(after the preamble) which is not the sum of two of the 25 numbers before it. What is the first
+number that does not have this property?
+*/
+fn sums(queue: &VecDeque<usize>) -> HashSet<usize> {
+    let mut sums = HashSet::new();
+    for i in 0..queue.len() {
+        for j in 1..queue.len() {
+            sums.insert(queue[i] + queue[j]);
+        }
+    }
+
+    trace!("{:?}", sums);
+    sums
+}
+
+/// Length of the preamble of the puzzle input. The example uses 5.
+pub const PREAMBLE: usize = 25;
+
+/// Parses the XMAS data, one number per line.
+pub fn parse_input(input: &str) -> ParseResult<Vec<usize>> {
+    let input = &*input::normalize(input);
+    input
+        .lines()
+        .map(|line| parse::value(input, line, "a number"))
+        .collect()
+}
+
+/// The first number after the `preamble` that is not the sum of two of the `preamble` numbers
+/// before it. Returns 0 if every number is such a sum.
+pub fn part_one(numbers: &[usize], preamble: usize) -> usize {
+    let mut queue = VecDeque::new();
+    for &i in numbers {
+        if queue.len() == preamble {
+            if !sums(&queue).contains(&i) {
+                return i;
+            }
+
+            queue.pop_front();
+        }
+
+        queue.push_back(i);
+    }
+
+    0
+}
+
+/*
+--- Part Two ---
+
+The final step in breaking the XMAS encryption relies on the invalid number you just found: you must
+find a contiguous set of at least two numbers in your list which sum to the invalid number from step
+1.
+
+Again consider the above example:
+
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+
+In this list, adding up all of the numbers from 15 through 40 produces the invalid number from step
+1, 127. (Of course, the contiguous set of numbers in your actual list might be much longer.)
+
+To find the encryption weakness, add together the smallest and largest number in this contiguous
+range; in this example, these are 15 and 47, producing 62.
+
+What is the encryption weakness in your XMAS-encrypted list of numbers?
+*/
+/// Sum of the smallest and largest number in the first contiguous range of at least two numbers
+/// that adds up to `part_one_result`. Returns 0 if there is no such range.
+pub fn part_two(numbers: &[usize], part_one_result: usize) -> usize {
+    let mut queue = VecDeque::new();
+    let mut iter = numbers.iter().copied();
+    match iter.next() {
+        Some(i) => queue.push_back(i),
+        _ => return 0,
+    }
+    while !queue.is_empty() {
+        trace!("{:?}", queue);
+        let sum: usize = queue.iter().sum();
+        if sum == 0 {
+            trace!("{:?}", queue);
+        }
+
+        // The range needs at least two numbers
+        if sum == part_one_result && queue.len() > 1 {
+            return queue.iter().min().unwrap() + queue.iter().max().unwrap();
+        } else if sum <= part_one_result || queue.len() == 1 {
+            trace!("{} <= {}, growing head", sum, part_one_result);
+            match iter.next() {
+                Some(i) => queue.push_back(i),
+                _ => return 0,
+            }
+        } else {
+            trace!("{} > {}, shrinking tail", sum, part_one_result);
+            queue.pop_front();
+        }
+    }
+
+    0
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Encoding Error";
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input, PREAMBLE).into()
+    }
+
+    // Part two needs the invalid number found in part one.
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input, part_one(input, PREAMBLE)).into()
+    }
+}
+
+impl Generate for Day09 {
+    const SIZE: usize = 1000;
+    // Every number is the sum of two of the ones before it, so at least every 25 numbers the
+    // smallest in the window doubles. Not much further than this they stop fitting in 64 bits.
+    const MAX_SIZE: usize = 1500;
+
+    // The invalid number is the sum of a few numbers of the preamble, and comes once all numbers
+    // in its window are more than half of it: then no two of them add up to it.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.min(Self::MAX_SIZE);
+        let mut numbers: Vec<usize> = (1..=2 * PREAMBLE).collect();
+        rng.shuffle(&mut numbers);
+        numbers.truncate(PREAMBLE);
+
+        let start = rng.below(PREAMBLE - 4);
+        let invalid: usize = numbers[start..start + rng.range(2..=4) as usize]
+            .iter()
+            .sum();
+        let mut placed = false;
+        while numbers.len() < size || !placed {
+            let window = &numbers[numbers.len() - PREAMBLE..];
+            if !placed && window.iter().all(|&n| 2 * n > invalid) && rng.chance(0.2) {
+                numbers.push(invalid);
+                placed = true;
+                continue;
+            }
+            let a = rng.below(PREAMBLE);
+            let b = (a + 1 + rng.below(PREAMBLE - 1)) % PREAMBLE;
+            numbers.push(window[a] + window[b]);
+        }
+
+        numbers.iter().map(|n| format!("{}\n", n)).collect()
+    }
+}
+
+impl Reference for Day09 {
+    const REFERENCE_SIZE: usize = 300;
+
+    fn reference_part_one(numbers: &Self::Input) -> Option<Answer> {
+        let invalid = (PREAMBLE..numbers.len())
+            .map(|i| (&numbers[i - PREAMBLE..i], numbers[i]))
+            .find(|(window, n)| {
+                !(0..window.len())
+                    .any(|a| (a + 1..window.len()).any(|b| window[a] + window[b] == *n))
+            });
+
+        Some(invalid.map_or(0, |(_, n)| n).into())
+    }
+
+    // Tries every range of at least two numbers, from the first number on.
+    fn reference_part_two(numbers: &Self::Input) -> Option<Answer> {
+        let invalid = match Self::reference_part_one(numbers) {
+            Some(Answer::Number(invalid)) => invalid as usize,
+            _ => return None,
+        };
+        for start in 0..numbers.len() {
+            for end in start + 2..=numbers.len() {
+                let range = &numbers[start..end];
+                if range.iter().sum::<usize>() == invalid {
+                    let min = range.iter().min().unwrap();
+                    let max = range.iter().max().unwrap();
+                    return Some((min + max).into());
+                }
+            }
+        }
+
+        Some(0.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+";
+
+    #[test]
+    fn example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        let invalid = part_one(&input, 5);
+        assert_eq!(invalid, 127);
+        assert_eq!(part_two(&input, invalid), 62);
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day09::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day09::part_one(&input), Answer::Number(373803594));
+        assert_eq!(Day09::part_two(&input), Answer::Number(51152360));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day09::parse(&Day09::generate(&mut Rng::new(seed), 500)).unwrap();
+            assert_eq!(input.len(), 500);
+            let invalid = part_one(&input, PREAMBLE);
+            assert_ne!(invalid, 0);
+            assert_ne!(part_two(&input, invalid), 0);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day09>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day09>(EXAMPLE);
+    }
+}
@@ -0,0 +1,459 @@
+//! Solution to [Day 10: Adapter Array](https://adventofcode.com/2020/day/10).
+
+use common::input;
+use common::parse::{self, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::trace;
+use std::collections::HashMap;
+
+/*
+--- Day 10: Adapter Array ---
+
+Patched into the aircraft's data port, you discover weather forecasts of a massive tropical storm.
+Before you can figure out whether it will impact your vacation plans, however, your device suddenly
+turns off!
+
+Its battery is dead.
+
+You'll need to plug it in. There's only one problem: the charging outlet near your seat produces the
+wrong number of jolts. Always prepared, you make a list of all of the joltage adapters in your bag.
+
+Each of your joltage adapters is rated for a specific output joltage (your puzzle input). Any given
+adapter can take an input 1, 2, or 3 jolts lower than its rating and still produce its rated output
+joltage.
+
+In addition, your device has a built-in joltage adapter rated for 3 jolts higher than the
+highest-rated adapter in your bag. (If your adapter list were 3, 9, and 6, your device's built-in
+adapter would be rated for 12 jolts.)
+
+Treat the charging outlet near your seat as having an effective joltage rating of 0.
+
+Since you have some time to kill, you might as well test all of your adapters. Wouldn't want to get
+to your resort and realize you can't even charge your device!
+
+If you use every adapter in your bag at once, what is the distribution of joltage differences
+between the charging outlet, the adapters, and your device?
+
+For example, suppose that in your bag, you have adapters with the following joltage ratings:
+
+16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4
+
+With these adapters, your device's built-in joltage adapter would be rated for 19 + 3 = 22 jolts,
+3 higher than the highest-rated adapter.
+
+Because adapters can only connect to a source 1-3 jolts lower than its rating, in order to use every
+adapter, you'd need to choose them like this:
+
+    The charging outlet has an effective rating of 0 jolts, so the only adapters that could connect
+        to it directly would need to have a joltage rating of 1, 2, or 3 jolts. Of these, only one
+        you have is an adapter rated 1 jolt (difference of 1).
+    From your 1-jolt rated adapter, the only choice is your 4-jolt rated adapter (difference of 3).
+    From the 4-jolt rated adapter, the adapters rated 5, 6, or 7 are valid choices. However, in
+        order to not skip any adapters, you have to pick the adapter rated 5 jolts (difference of
+        1).
+    Similarly, the next choices would need to be the adapter rated 6 and then the adapter rated 7
+        (with difference of 1 and 1).
+    The only adapter that works with the 7-jolt rated adapter is the one rated 10 jolts (difference
+        of 3).
+    From 10, the choices are 11 or 12; choose 11 (difference of 1) and then 12 (difference of 1).
+    After 12, only valid adapter has a rating of 15 (difference of 3), then 16 (difference of 1),
+        then 19 (difference of 3).
+    Finally, your device's built-in adapter is always 3 higher than the highest adapter, so its
+        rating is 22 jolts (always a difference of 3).
+
+In this example, when using every adapter, there are 7 differences of 1 jolt and 5 differences of
+3 jolts.
+
+Here is a larger example:
+
+28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3
+
+In this larger example, in a chain that uses all of the adapters, there are 22 differences of 1 jolt
+and 10 differences of 3 jolts.
+
+Find a chain that uses all of your adapters to connect the charging outlet to your device's built-in
+adapter and count the joltage differences between the charging outlet, the adapters, and your
+device. What is the number of 1-jolt differences multiplied by the number of 3-jolt differences?
+*/
+
+/// Parses the adapter joltages, one per line.
+pub fn parse_input(input: &str) -> ParseResult<Vec<i64>> {
+    let input = &*input::normalize(input);
+    input
+        .lines()
+        .map(|line| parse::value(input, line, "an adapter joltage"))
+        .collect()
+}
+
+/// Number of 1-jolt differences multiplied by the number of 3-jolt differences when all adapters
+/// are chained together.
+pub fn part_one(adapters: &[i64]) -> usize {
+    let mut list = adapters.to_vec();
+    list.push(0); // Charging outlet
+    list.sort();
+    list.push(list.last().unwrap() + 3); // My device
+    trace!("Chain: {:?}", list);
+    let a = list.iter().zip(list.iter().skip(1)).map(|x| x.1 - x.0);
+    let one = a.clone().filter(|x| x == &1).count();
+    let three = a.clone().filter(|x| x == &3).count();
+    let result = one * three;
+    trace!("{}(1 jolts) * {}(3 jolts) = {}", one, three, result);
+    result
+}
+
+/*
+--- Part Two ---
+
+To completely determine whether you have enough adapters, you'll need to figure out how many
+different ways they can be arranged. Every arrangement needs to connect the charging outlet to your
+device. The previous rules about when adapters can successfully connect still apply.
+
+The first example above (the one that starts with 16, 10, 15) supports the following arrangements:
+
+(0), 1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19, (22)
+(0), 1, 4, 5, 6, 7, 10, 12, 15, 16, 19, (22)
+(0), 1, 4, 5, 7, 10, 11, 12, 15, 16, 19, (22)
+(0), 1, 4, 5, 7, 10, 12, 15, 16, 19, (22)
+(0), 1, 4, 6, 7, 10, 11, 12, 15, 16, 19, (22)
+(0), 1, 4, 6, 7, 10, 12, 15, 16, 19, (22)
+(0), 1, 4, 7, 10, 11, 12, 15, 16, 19, (22)
+(0), 1, 4, 7, 10, 12, 15, 16, 19, (22)
+
+(The charging outlet and your device's built-in adapter are shown in parentheses.) Given the
+adapters from the first example, the total number of arrangements that connect the charging outlet
+to your device is 8.
+
+The second example above (the one that starts with 28, 33, 18) has many arrangements. Here are a
+few:
+
+(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
+32, 33, 34, 35, 38, 39, 42, 45, 46, 47, 48, 49, (52)
+
+(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
+32, 33, 34, 35, 38, 39, 42, 45, 46, 47, 49, (52)
+
+(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
+32, 33, 34, 35, 38, 39, 42, 45, 46, 48, 49, (52)
+
+(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
+32, 33, 34, 35, 38, 39, 42, 45, 46, 49, (52)
+
+(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
+32, 33, 34, 35, 38, 39, 42, 45, 47, 48, 49, (52)
+
+(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
+46, 48, 49, (52)
+
+(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
+46, 49, (52)
+
+(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
+47, 48, 49, (52)
+
+(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
+47, 49, (52)
+
+(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
+48, 49, (52)
+
+In total, this set of adapters can connect the charging outlet to your device in 19208 distinct
+arrangements.
+
+You glance back down at your bag and try to remember why you brought so many adapters; there must be
+more than a trillion valid ways to arrange them! Surely, there must be an efficient way to count the
+arrangements.
+
+What is the total number of distinct ways you can arrange the adapters to connect the charging
+outlet to your device?
+*/
+
+// OK, so I'm thinking in delta's. I'm also assuming there are no duplicate adapters.
+// we already know how to get the amount of 1-, 2-, and 3-jolt differences.
+// Then if we combine that with the known begin and end jolts,
+// We only need to know the permutations of sums of those differences that equal the end jolts.
+// ...No, that would require the adapters to be interchangeable based on jolt difference.
+// Build a tree where every adapter node has a list of compatible other adapters?
+// It's like building a pyramid (upside-down).
+// I believe there is a matchstick game problem though.
+// For i in 0..end: Can we build to i using any set of adapters?
+// Number of ways to get from 0..n?
+// One way to get from 0 to 1
+// One way to get from n-3 to n
+// Number of ways to get from 0 to 3 is sum of number of ways from 0-1 * 1-2 * 2-3, 0-1 * 2-3,
+// For every adapter, count the number of ways to get to n-3, n-2, and n-1.
+// What is the number of (partial) orderings such that:
+// 1. y - x >= 3
+// 2. x < y
+// Back to the delta's. We can make a list of all delta's.
+//
+/// Number of distinct arrangements of adapters that connect the outlet to the device.
+pub fn part_two(adapters: &[i64]) -> usize {
+    let mut list = adapters.to_vec();
+    list.push(0); // Charging outlet
+    list.sort();
+    let device_joltage = list.last().unwrap() + 3;
+    list.push(device_joltage);
+
+    // Four ways to make a jump:
+    // (0 -> 3)
+    // (0 -> 1 -> 3)
+    // (0 -> 2 -> 3)
+    // (0 -> 1 -> 2 -> 3)
+    let mut set = HashMap::<&i64, usize>::new();
+
+    // 0: 1
+    // 1: 1
+    // 2: 1 + 1 if 1 exists
+    // 3: 1
+    for i in &list {
+        set.insert(i, 0);
+        let mut count = 0;
+        for j in 1..=3 {
+            // 0 (1): -3, -2, -1
+            // 1 (1): -2, -1, 0
+            // 2 (2): -1, 0, 1
+            // 3 (4): 0, 1, 2
+            let offset = i - j;
+            if list.contains(&offset) {
+                count += set[&offset];
+            }
+        }
+        if count == 0 {
+            count = 1;
+        }
+        set.insert(i, count);
+    }
+
+    for i in &list {
+        trace!("{}: {}", i, set[&i]);
+    }
+
+    set[&list.last().unwrap()]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Adapter Array";
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day10 {
+    const SIZE: usize = 94;
+
+    // Like the real input, the differences are all 1 or 3. A run of `n` differences of 1
+    // multiplies the number of arrangements by the `n`th tribonacci number; once that gets too
+    // close to overflowing, only differences of 3 are added.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const WAYS: [usize; 5] = [1, 1, 2, 4, 7];
+        let mut adapters = Vec::new();
+        let mut joltage = 0;
+        let mut arrangements: usize = 1;
+        while adapters.len() < size {
+            let run = rng.below(WAYS.len()).min(size - adapters.len() - 1);
+            match arrangements.checked_mul(WAYS[run]) {
+                Some(n) if n < 1 << 62 => {
+                    arrangements = n;
+                    for _ in 0..run {
+                        joltage += 1;
+                        adapters.push(joltage);
+                    }
+                }
+                _ => (),
+            }
+            joltage += 3;
+            adapters.push(joltage);
+        }
+        rng.shuffle(&mut adapters);
+
+        adapters.iter().map(|a| format!("{}\n", a)).collect()
+    }
+}
+
+// The chain from the outlet to the device, with all adapters in order.
+fn reference_chain(adapters: &[i64]) -> Vec<i64> {
+    let mut chain = vec![0];
+    chain.extend(adapters);
+    chain.sort_unstable();
+    chain.push(chain.last().unwrap() + 3);
+
+    chain
+}
+
+impl Reference for Day10 {
+    const REFERENCE_SIZE: usize = 100;
+
+    fn reference_part_one(adapters: &Self::Input) -> Option<Answer> {
+        let chain = reference_chain(adapters);
+        let count = |difference| {
+            chain
+                .windows(2)
+                .filter(|w| w[1] - w[0] == difference)
+                .count()
+        };
+
+        Some((count(1) * count(3)).into())
+    }
+
+    // Counts the arrangements that end at each adapter, from those that end at the adapters
+    // before it.
+    fn reference_part_two(adapters: &Self::Input) -> Option<Answer> {
+        let chain = reference_chain(adapters);
+        let mut ways = vec![1u64; 1];
+        for i in 1..chain.len() {
+            let to_here = (0..i)
+                .filter(|&j| chain[i] - chain[j] <= 3)
+                .map(|j| ways[j])
+                .sum();
+            ways.push(to_here);
+        }
+
+        Some(ways.last().copied().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4
+";
+    const EXAMPLE_LARGER: &str = "\
+28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3
+";
+
+    #[test]
+    fn example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part_one(&input), Answer::Number(35));
+        assert_eq!(Day10::part_two(&input), Answer::Number(8));
+    }
+
+    #[test]
+    fn example_larger() {
+        let input = Day10::parse(EXAMPLE_LARGER).unwrap();
+        assert_eq!(Day10::part_one(&input), Answer::Number(220));
+        assert_eq!(Day10::part_two(&input), Answer::Number(19208));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day10::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day10::part_one(&input), Answer::Number(1914));
+        assert_eq!(Day10::part_two(&input), Answer::Number(9256148959232));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day10::parse(&Day10::generate(&mut Rng::new(seed), 200)).unwrap();
+            assert_eq!(input.len(), 200);
+            assert!(part_two(&input) > 0);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day10>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day10>(EXAMPLE);
+    }
+}
@@ -0,0 +1,611 @@
+//! Solution to [Day 11: Seating System](https://adventofcode.com/2020/day/11).
+
+/*
+--- Day 11: Seating System ---
+
+Your plane lands with plenty of time to spare. The final leg of your journey is a ferry that goes
+directly to the tropical island where you can finally start your vacation. As you reach the waiting
+area to board the ferry, you realize you're so early, nobody else has even arrived yet!
+
+By modeling the process people use to choose (or abandon) their seat in the waiting area, you're
+pretty sure you can predict the best place to sit. You make a quick map of the seat layout (your
+    puzzle input).
+
+The seat layout fits neatly on a grid. Each position is either floor (.), an empty seat (L), or an
+occupied seat (#). For example, the initial seat layout might look like this:
+
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+
+Now, you just need to model the people who will be arriving shortly. Fortunately, people are
+entirely predictable and always follow a simple set of rules. All decisions are based on the number
+of occupied seats adjacent to a given seat (one of the eight positions immediately up, down, left,
+right, or diagonal from the seat). The following rules are applied to every seat simultaneously:
+
+    If a seat is empty (L) and there are no occupied seats adjacent to it, the seat becomes
+        occupied.
+    If a seat is occupied (#) and four or more seats adjacent to it are also occupied, the seat
+        becomes empty.
+    Otherwise, the seat's state does not change.
+
+Floor (.) never changes; seats don't move, and nobody sits on the floor.
+
+After one round of these rules, every seat in the example layout becomes occupied:
+
+#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##
+
+After a second round, the seats with four or more occupied adjacent seats become empty again:
+
+#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##
+
+This process continues for three more rounds:
+
+#.##.L#.##
+#L###LL.L#
+L.#.#..#..
+#L##.##.L#
+#.##.LL.LL
+#.###L#.##
+..#.#.....
+#L######L#
+#.LL###L.L
+#.#L###.##
+
+#.#L.L#.##
+#LLL#LL.L#
+L.L.L..#..
+#LLL.##.L#
+#.LL.LL.LL
+#.LL#L#.##
+..L.L.....
+#L#LLLL#L#
+#.LLLLLL.L
+#.#L#L#.##
+
+#.#L.L#.##
+#LLL#LL.L#
+L.#.L..#..
+#L##.##.L#
+#.#L.LL.LL
+#.#L#L#.##
+..L.L.....
+#L#L##L#L#
+#.LLLLLL.L
+#.#L#L#.##
+
+At this point, something interesting happens: the chaos stabilizes and further applications of these
+rules cause no seats to change state! Once people stop moving around, you count 37 occupied seats.
+
+Simulate your seating area by applying the seating rules repeatedly until no seats change state. How
+many seats end up occupied?
+*/
+
+use common::grid::Cell;
+use common::input;
+use common::simulation::Simulation;
+use common::{Answer, Generate, Grid, ParseResult, Part, Reference, Rng, Solution, Vec2};
+use log::{debug, trace};
+use std::collections::HashSet;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Position {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl Cell for Position {
+    const EXPECTED: &'static str = "`.`, `L` or `#`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Position::Floor),
+            'L' => Some(Position::EmptySeat),
+            '#' => Some(Position::OccupiedSeat),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Position::Floor => '.',
+            Position::EmptySeat => 'L',
+            Position::OccupiedSeat => '#',
+        }
+    }
+}
+
+/// The seating layout.
+pub type SeatingArea = Grid<Position>;
+
+/// Parses the seat layout: `.` for floor, `L` for an empty seat and `#` for an occupied seat.
+pub fn parse_input(input: &str) -> ParseResult<SeatingArea> {
+    let input = &*input::normalize(input);
+    let area = Grid::parse(input)?;
+    debug!("width: {}, height: {}", area.width(), area.height());
+
+    Ok(area)
+}
+
+// One round of the seating rules. An empty seat becomes occupied if `occupied` counts none
+// around it; an occupied seat is emptied if it counts `tolerance` or more.
+fn step<F>(seating_area: &SeatingArea, tolerance: usize, occupied: &F) -> SeatingArea
+where
+    F: Fn(&SeatingArea, i64, i64) -> usize,
+{
+    let mut iteration = seating_area.clone();
+    // Iterate cells
+    for ((x, y), &pos) in seating_area.iter() {
+        // rules
+        let next = match pos {
+            Position::EmptySeat => {
+                if occupied(seating_area, x, y) == 0 {
+                    Position::OccupiedSeat
+                } else {
+                    pos
+                }
+            }
+            Position::OccupiedSeat => {
+                if occupied(seating_area, x, y) >= tolerance {
+                    Position::EmptySeat
+                } else {
+                    pos
+                }
+            }
+            _ => pos,
+        };
+        iteration.set(x, y, next);
+    }
+
+    iteration
+}
+
+fn count_occupied(seating_area: &SeatingArea) -> usize {
+    seating_area
+        .cells()
+        .iter()
+        .filter(|&&pos| pos == Position::OccupiedSeat)
+        .count()
+}
+
+/// Applies the seating rules until nobody moves anymore, and counts the occupied seats. Returns
+/// `None` if the layout never settles but keeps going round through the same states.
+fn simulate<F>(area: &SeatingArea, tolerance: usize, occupied: F) -> Option<usize>
+where
+    F: Fn(&SeatingArea, i64, i64) -> usize,
+{
+    let mut seating_area = area.clone();
+    let mut seen = HashSet::new();
+
+    trace!("Initial layout:\n{}", seating_area);
+    let mut num_iterations = 0;
+    loop {
+        let iteration = step(&seating_area, tolerance, &occupied);
+        num_iterations += 1;
+        trace!(
+            "num_iterations: {}, num_seats: {}\n{}",
+            num_iterations,
+            count_occupied(&iteration),
+            iteration
+        );
+
+        if iteration == seating_area {
+            break;
+        }
+        if !seen.insert(seating_area) {
+            debug!("Repeated a layout after {} iterations", num_iterations);
+            return None;
+        }
+        seating_area = iteration;
+    }
+    debug!("Iterations: {}", num_iterations);
+
+    Some(count_occupied(&seating_area))
+}
+
+// The eight adjacent seats.
+fn adjacent(seating_area: &SeatingArea, x: i64, y: i64) -> usize {
+    seating_area
+        .neighbours_8(x, y)
+        .filter(|(_, &pos)| pos == Position::OccupiedSeat)
+        .count()
+}
+
+// The first seat in each of the eight directions, looking past the floor.
+fn visible(seating_area: &SeatingArea, x: i64, y: i64) -> usize {
+    Vec2::NEIGHBOURS_8
+        .iter()
+        .filter(|direction| {
+            seating_area
+                .ray(x, y, direction.x, direction.y)
+                .map(|(_, &pos)| pos)
+                .find(|&pos| pos != Position::Floor)
+                == Some(Position::OccupiedSeat)
+        })
+        .count()
+}
+
+/// Number of occupied seats once the layout stops changing, when people only look at adjacent
+/// seats, or `None` if it never does.
+pub fn part_one(area: &SeatingArea) -> Option<usize> {
+    simulate(area, 4, adjacent)
+}
+
+/*
+--- Part Two ---
+
+As soon as people start to arrive, you realize your mistake. People don't just care about adjacent
+seats - they care about the first seat they can see in each of those eight directions!
+
+Now, instead of considering just the eight immediately adjacent seats, consider the first seat in
+each of those eight directions. For example, the empty seat below would see eight occupied seats:
+
+.......#.
+...#.....
+.#.......
+.........
+..#L....#
+....#....
+.........
+#........
+...#.....
+
+The leftmost empty seat below would only see one empty seat, but cannot see any of the occupied
+ones:
+
+.............
+.L.L.#.#.#.#.
+.............
+
+The empty seat below would see no occupied seats:
+
+.##.##.
+#.#.#.#
+##...##
+...L...
+##...##
+#.#.#.#
+.##.##.
+
+Also, people seem to be more tolerant than you expected: it now takes five or more visible occupied
+seats for an occupied seat to become empty (rather than four or more from the previous rules). The
+other rules still apply: empty seats that see no occupied seats become occupied, seats matching no
+rule don't change, and floor never changes.
+
+Given the same starting layout as above, these new rules cause the seating area to shift around as
+follows:
+
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+
+#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##
+
+#.LL.LL.L#
+#LLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLLL.L
+#.LLLLL.L#
+
+#.L#.##.L#
+#L#####.LL
+L.#.#..#..
+##L#.##.##
+#.##.#L.##
+#.#####.#L
+..#.#.....
+LLL####LL#
+#.L#####.L
+#.L####.L#
+
+#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##LL.LL.L#
+L.LL.LL.L#
+#.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLL#.L
+#.L#LL#.L#
+
+#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##L#.#L.L#
+L.L#.#L.L#
+#.L####.LL
+..#.#.....
+LLL###LLL#
+#.LLLLL#.L
+#.L#LL#.L#
+
+#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##L#.#L.L#
+L.L#.LL.L#
+#.LLLL#.LL
+..#.L.....
+LLL###LLL#
+#.LLLLL#.L
+#.L#LL#.L#
+
+Again, at this point, people stop shifting around and the seating area reaches equilibrium. Once
+this occurs, you count 26 occupied seats.
+
+Given the new visibility method and the rule change for occupied seats becoming empty, once
+equilibrium is reached, how many seats end up occupied?
+*/
+
+/// Number of occupied seats once the layout stops changing, when people look at the first seat
+/// in each direction, or `None` if it never does.
+pub fn part_two(area: &SeatingArea) -> Option<usize> {
+    simulate(area, 5, visible)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Seating System";
+
+    type Input = SeatingArea;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+// Whether the rules stop changing the layout. They do not always: some layouts end up flipping
+// back and forth between two states.
+fn settles<F>(area: &SeatingArea, tolerance: usize, occupied: F) -> bool
+where
+    F: Fn(&SeatingArea, i64, i64) -> usize,
+{
+    simulate(area, tolerance, occupied).is_some()
+}
+
+impl Simulation for Day11 {
+    type State = SeatingArea;
+
+    const COUNTED: &'static str = "occupied seats";
+
+    fn start(area: &SeatingArea, _part: Part) -> SeatingArea {
+        area.clone()
+    }
+
+    fn step(_area: &SeatingArea, state: &SeatingArea, part: Part) -> Option<SeatingArea> {
+        let next = match part {
+            Part::One => step(state, 4, &adjacent),
+            Part::Two => step(state, 5, &visible),
+        };
+        if next == *state {
+            None
+        } else {
+            Some(next)
+        }
+    }
+
+    fn count(state: &SeatingArea) -> usize {
+        count_occupied(state)
+    }
+
+    fn render(state: &SeatingArea) -> String {
+        state.to_string()
+    }
+
+    fn repeats(earlier: &SeatingArea, state: &SeatingArea) -> bool {
+        earlier == state
+    }
+}
+
+impl Generate for Day11 {
+    const SIZE: usize = 99;
+
+    // The size is the number of rows, which are as wide as the real ones. Layouts that never
+    // settle in either part are thrown away; aisles make that rare.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        loop {
+            // Like the real layout, the seats are split up by aisles of mostly floor
+            let aisles: Vec<bool> = (0..92).map(|_| rng.chance(0.15)).collect();
+            let mut area = String::new();
+            for _ in 0..size.max(1) {
+                let aisle_row = rng.chance(0.12);
+                for &aisle in &aisles {
+                    let floor = match (aisle, aisle_row) {
+                        (false, false) => 0.04,
+                        _ => 0.7,
+                    };
+                    area.push(if rng.chance(floor) { '.' } else { 'L' });
+                }
+                area.push('\n');
+            }
+
+            let layout = parse_input(&area).unwrap();
+            if settles(&layout, 4, adjacent) && settles(&layout, 5, visible) {
+                return area;
+            }
+        }
+    }
+}
+
+// Plays the rules on rows of characters, looking `reach` seats far in each direction (or as far
+// as the layout goes), until nothing changes. Returns the number of occupied seats, or `None`
+// once a layout comes back.
+fn reference_simulate(area: &SeatingArea, reach: i64, tolerance: usize) -> Option<usize> {
+    let mut rows: Vec<Vec<char>> = area
+        .to_string()
+        .lines()
+        .map(|l| l.chars().collect())
+        .collect();
+    let (height, width) = (rows.len() as i64, rows[0].len() as i64);
+    let mut history = Vec::new();
+    loop {
+        let mut next = rows.clone();
+        for y in 0..height {
+            for x in 0..width {
+                let mut occupied = 0;
+                for direction in &Vec2::NEIGHBOURS_8 {
+                    for distance in 1..=reach {
+                        let (sx, sy) = (x + direction.x * distance, y + direction.y * distance);
+                        if sx < 0 || sy < 0 || sx >= width || sy >= height {
+                            break;
+                        }
+                        match rows[sy as usize][sx as usize] {
+                            '#' => occupied += 1,
+                            'L' => (),
+                            _ => continue,
+                        }
+                        break;
+                    }
+                }
+                let seat = &mut next[y as usize][x as usize];
+                if *seat == 'L' && occupied == 0 {
+                    *seat = '#';
+                } else if *seat == '#' && occupied >= tolerance {
+                    *seat = 'L';
+                }
+            }
+        }
+        if next == rows {
+            return Some(rows.iter().flatten().filter(|&&c| c == '#').count());
+        }
+        if history.contains(&next) {
+            return None;
+        }
+        history.push(rows);
+        rows = next;
+    }
+}
+
+impl Reference for Day11 {
+    const REFERENCE_SIZE: usize = 8;
+
+    fn reference_part_one(area: &Self::Input) -> Option<Answer> {
+        Some(reference_simulate(area, 1, 4).into())
+    }
+
+    fn reference_part_two(area: &Self::Input) -> Option<Answer> {
+        Some(reference_simulate(area, i64::MAX, 5).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::simulation;
+
+    #[test]
+    fn example() {
+        let input = Day11::parse(include_str!("../example")).unwrap();
+        assert_eq!(Day11::part_one(&input), Answer::Number(37));
+        assert_eq!(Day11::part_two(&input), Answer::Number(26));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day11::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day11::part_one(&input), Answer::Number(2321));
+        assert_eq!(Day11::part_two(&input), Answer::Number(2102));
+    }
+
+    #[test]
+    fn oscillating() {
+        // Without its 67th row, the real layout ends up flipping between two states
+        let input: String = include_str!("../input")
+            .lines()
+            .enumerate()
+            .filter(|&(i, _)| i != 66)
+            .map(|(_, line)| format!("{}\n", line))
+            .collect();
+        let input = Day11::parse(&input).unwrap();
+        assert_eq!(Day11::part_one(&input), Answer::Unsolved);
+        assert_eq!(Day11::reference_part_one(&input), Some(Answer::Unsolved));
+        assert_eq!(
+            simulation::run_to_end::<Day11>(&input, Part::One, 1000),
+            None
+        );
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day11::parse(&Day11::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!((input.width(), input.height()), (92, 20));
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day11>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day11>(include_str!("../example"));
+    }
+
+    #[test]
+    fn simulation() {
+        let input = Day11::parse(include_str!("../example")).unwrap();
+        let one = simulation::run_to_end::<Day11>(&input, Part::One, 100);
+        let two = simulation::run_to_end::<Day11>(&input, Part::Two, 100);
+        assert_eq!((one, two), (Some(37), Some(26)));
+    }
+}
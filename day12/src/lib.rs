@@ -0,0 +1,522 @@
+//! Solution to [Day 12: Rain Risk](https://adventofcode.com/2020/day/12).
+
+/*
+--- Day 12: Rain Risk ---
+
+Your ferry made decent progress toward the island, but the storm came in faster than anyone
+expected. The ferry needs to take evasive actions!
+
+Unfortunately, the ship's navigation computer seems to be malfunctioning; rather than giving a route
+directly to safety, it produced extremely circuitous instructions. When the captain uses the PA
+system to ask if anyone can help, you quickly volunteer.
+
+The navigation instructions (your puzzle input) consists of a sequence of single-character actions
+paired with integer input values. After staring at them for a few minutes, you work out what they
+probably mean:
+
+    Action N means to move north by the given value.
+    Action S means to move south by the given value.
+    Action E means to move east by the given value.
+    Action W means to move west by the given value.
+    Action L means to turn left the given number of degrees.
+    Action R means to turn right the given number of degrees.
+    Action F means to move forward by the given value in the direction the ship is currently facing.
+
+The ship starts by facing east. Only the L and R actions change the direction the ship is facing.
+(That is, if the ship is facing east and the next instruction is N10, the ship would move north 10
+    units, but would still move east if the following action were F.)
+
+For example:
+
+F10
+N3
+F7
+R90
+F11
+
+These instructions would be handled as follows:
+
+    F10 would move the ship 10 units east (because the ship starts by facing east) to east 10,
+    north 0.
+    N3 would move the ship 3 units north to east 10, north 3.
+    F7 would move the ship another 7 units east (because the ship is still facing east) to east 17,
+    north 3.
+    R90 would cause the ship to turn right by 90 degrees and face south; it remains at east 17,
+    north 3.
+    F11 would move the ship 11 units south to east 17, south 8.
+
+At the end of these instructions, the ship's Manhattan distance (sum of the absolute values of its east/west position and its north/south position) from its starting position is 17 + 8 = 25.
+
+Figure out where the navigation instructions lead. What is the Manhattan distance between that location and the ship's starting position?
+
+*/
+
+use common::input;
+use common::parse::{self, ParseError, ParseResult};
+use common::simulation::Simulation;
+use common::{Answer, Direction, Generate, Part, Reference, Rng, Solution, Vec2};
+use log::{debug, trace};
+
+/// What a navigation instruction does. `Left` and `Right` turn by a number of degrees.
+#[derive(Debug, Copy, Clone)]
+pub enum Action {
+    North,
+    South,
+    East,
+    West,
+    Left,  // Degrees!
+    Right, // Degrees!
+    Forward,
+}
+
+// The direction a move action goes in.
+fn direction(action: Action) -> Direction {
+    match action {
+        Action::North => Direction::North,
+        Action::South => Direction::South,
+        Action::East => Direction::East,
+        Action::West => Direction::West,
+        _ => unreachable!(),
+    }
+}
+
+// The number of quarter turns to the left a turn action makes.
+fn quarter_turns(instruction: Instruction) -> i64 {
+    let degrees = i64::from(instruction.value);
+    match instruction.action {
+        Action::Left => degrees / 90,
+        Action::Right => -degrees / 90,
+        _ => unreachable!(),
+    }
+}
+
+/// A single navigation instruction.
+#[derive(Debug, Copy, Clone)]
+pub struct Instruction {
+    pub action: Action,
+    pub value: i32,
+}
+
+// East is positive x, north is positive y.
+#[derive(Debug, Copy, Clone)]
+struct Ship {
+    position: Vec2,
+    heading: Direction,
+}
+
+/// Parses the navigation instructions, one per line. Turns must be multiples of 90 degrees.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Instruction>> {
+    let input = &*input::normalize(input);
+    let mut instructions = Vec::new();
+    for line in input.lines() {
+        if !line.is_char_boundary(1) {
+            return Err(ParseError::at(input, line, "an action and a value"));
+        }
+        let tuple = line.split_at(1);
+        let action = match tuple.0 {
+            "N" => Action::North,
+            "S" => Action::South,
+            "E" => Action::East,
+            "W" => Action::West,
+            "L" => Action::Left,
+            "R" => Action::Right,
+            "F" => Action::Forward,
+            _ => return Err(ParseError::at(input, tuple.0, "one of `NSEWLRF`")),
+        };
+        let value = parse::value(input, tuple.1, "a number")?;
+        // We can only turn in steps of 90 degrees
+        if let Action::Left | Action::Right = action {
+            if value % 90 != 0 {
+                return Err(ParseError::at(input, tuple.1, "a multiple of 90 degrees"));
+            }
+        }
+        instructions.push(Instruction { action, value });
+    }
+
+    Ok(instructions)
+}
+
+fn eval(ship: Ship, instruction: Instruction) -> Ship {
+    let value = i64::from(instruction.value);
+    match instruction.action {
+        Action::North | Action::South | Action::East | Action::West => Ship {
+            position: ship.position + direction(instruction.action).offset() * value,
+            ..ship
+        },
+        Action::Left | Action::Right => Ship {
+            heading: ship.heading.turn(quarter_turns(instruction)),
+            ..ship
+        },
+        Action::Forward => Ship {
+            position: ship.position + ship.heading.offset() * value,
+            ..ship
+        },
+    }
+}
+
+/// Manhattan distance from the start after moving the ship itself.
+pub fn part_one(instructions: &[Instruction]) -> usize {
+    trace!("{:?}", instructions);
+    // We're starting in the east direction.
+    let mut ship = Ship {
+        position: Vec2::ZERO,
+        heading: Direction::East,
+    };
+    for &instruction in instructions {
+        let old = ship;
+        ship = eval(ship, instruction);
+        trace!("{:?} + {:?} = {:?}", old, instruction, ship);
+    }
+    debug!("{:?}", ship);
+
+    ship.position.manhattan() as usize
+}
+
+/*
+--- Part Two ---
+
+Before you can give the destination to the captain, you realize that the actual action meanings were
+printed on the back of the instructions the whole time.
+
+Almost all of the actions indicate how to move a waypoint which is relative to the ship's position:
+
+    Action N means to move the waypoint north by the given value.
+    Action S means to move the waypoint south by the given value.
+    Action E means to move the waypoint east by the given value.
+    Action W means to move the waypoint west by the given value.
+    Action L means to rotate the waypoint around the ship left (counter-clockwise) the given number
+    of degrees.
+    Action R means to rotate the waypoint around the ship right (clockwise) the given number of
+    degrees.
+    Action F means to move forward to the waypoint a number of times equal to the given value.
+
+The waypoint starts 10 units east and 1 unit north relative to the ship. The waypoint is relative to
+the ship; that is, if the ship moves, the waypoint moves with it.
+
+For example, using the same instructions as above:
+
+    F10 moves the ship to the waypoint 10 times (a total of 100 units east and 10 units north),
+        leaving the ship at east 100, north 10. The waypoint stays 10 units east and 1 unit north of
+        the ship.
+    N3 moves the waypoint 3 units north to 10 units east and 4 units north of the ship. The ship
+        remains at east 100, north 10.
+    F7 moves the ship to the waypoint 7 times (a total of 70 units east and 28 units north), leaving
+        the ship at east 170, north 38. The waypoint stays 10 units east and 4 units north of the
+        ship.
+    R90 rotates the waypoint around the ship clockwise 90 degrees, moving it to 4 units east and 10
+        units south of the ship. The ship remains at east 170, north 38.
+    F11 moves the ship to the waypoint 11 times (a total of 44 units east and 110 units south),
+        leaving the ship at east 214, south 72. The waypoint stays 4 units east and 10 units south
+        of the ship.
+
+After these operations, the ship's Manhattan distance from its starting position is 214 + 72 = 286.
+
+Figure out where the navigation instructions actually lead. What is the Manhattan distance between that location and the ship's starting position?
+*/
+
+// The waypoint is relative to the ship.
+#[derive(Debug, Copy, Clone)]
+struct Situation {
+    ship: Vec2,
+    waypoint: Vec2,
+}
+
+fn eval_two(situation: Situation, instruction: Instruction) -> Situation {
+    let value = i64::from(instruction.value);
+    match instruction.action {
+        // move the waypoint
+        Action::North | Action::South | Action::East | Action::West => Situation {
+            waypoint: situation.waypoint + direction(instruction.action).offset() * value,
+            ..situation
+        },
+        // rotate the waypoint around the ship
+        Action::Left | Action::Right => Situation {
+            waypoint: situation.waypoint.rotate(quarter_turns(instruction)),
+            ..situation
+        },
+        // move forward to the waypoint a number of times equal to the given value
+        Action::Forward => Situation {
+            ship: situation.ship + situation.waypoint * value,
+            ..situation
+        },
+    }
+}
+
+/// Manhattan distance from the start after moving the ship along its waypoint.
+pub fn part_two(instructions: &[Instruction]) -> usize {
+    trace!("{:?}", instructions);
+    let mut situation = Situation {
+        ship: Vec2::ZERO,
+        waypoint: Vec2::new(10, 1),
+    };
+    for &instruction in instructions {
+        let old = situation;
+        situation = eval_two(situation, instruction);
+        trace!("{:?} + {:?} = {:?}", old, instruction, situation);
+    }
+    debug!("{:?}", situation);
+
+    situation.ship.manhattan() as usize
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Rain Risk";
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+/// The ship partway through the instructions, for following along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Voyage {
+    /// Number of instructions followed so far.
+    pub followed: usize,
+    pub ship: Vec2,
+    /// Where the ship faces. Only used in part one.
+    pub heading: Direction,
+    /// Where the waypoint is, relative to the ship. Only used in part two.
+    pub waypoint: Vec2,
+    pub part: Part,
+}
+
+// A position as the puzzle text puts it.
+fn describe(position: Vec2) -> String {
+    let east = if position.x < 0 { "west" } else { "east" };
+    let north = if position.y < 0 { "south" } else { "north" };
+    format!(
+        "{} {}, {} {}",
+        east,
+        position.x.abs(),
+        north,
+        position.y.abs()
+    )
+}
+
+impl Simulation for Day12 {
+    type State = Voyage;
+
+    const COUNTED: &'static str = "Manhattan distance";
+
+    fn start(_instructions: &Self::Input, part: Part) -> Voyage {
+        Voyage {
+            followed: 0,
+            ship: Vec2::ZERO,
+            heading: Direction::East,
+            waypoint: Vec2::new(10, 1),
+            part,
+        }
+    }
+
+    fn step(instructions: &Self::Input, voyage: &Voyage, part: Part) -> Option<Voyage> {
+        let &instruction = instructions.get(voyage.followed)?;
+        let mut next = Voyage {
+            followed: voyage.followed + 1,
+            ..*voyage
+        };
+        match part {
+            Part::One => {
+                let ship = Ship {
+                    position: voyage.ship,
+                    heading: voyage.heading,
+                };
+                let ship = eval(ship, instruction);
+                next.ship = ship.position;
+                next.heading = ship.heading;
+            }
+            Part::Two => {
+                let situation = Situation {
+                    ship: voyage.ship,
+                    waypoint: voyage.waypoint,
+                };
+                let situation = eval_two(situation, instruction);
+                next.ship = situation.ship;
+                next.waypoint = situation.waypoint;
+            }
+        }
+
+        Some(next)
+    }
+
+    fn count(voyage: &Voyage) -> usize {
+        voyage.ship.manhattan() as usize
+    }
+
+    fn render(voyage: &Voyage) -> String {
+        let mut text = format!(
+            "Instructions followed: {}\nShip:     {}\n",
+            voyage.followed,
+            describe(voyage.ship)
+        );
+        match voyage.part {
+            Part::One => text += &format!("Heading:  {:?}\n", voyage.heading),
+            Part::Two => text += &format!("Waypoint: {}\n", describe(voyage.waypoint)),
+        }
+
+        text
+    }
+}
+
+impl Generate for Day12 {
+    const SIZE: usize = 764;
+    // Like in the real input, moves are 1 to 5 units and the ship goes forward 1 to 100 times.
+    // After `n` instructions the waypoint is then at most `5 * n + 11` units away, and the ship
+    // at most about `500 * n * n`, which stays in an `i64` up to 50 million instructions.
+    const MAX_SIZE: usize = 50_000_000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut instructions = String::new();
+        for _ in 0..size.min(Self::MAX_SIZE) {
+            let instruction = match rng.below(7) {
+                0..=3 => format!("{}{}", rng.choose(&['N', 'S', 'E', 'W']), rng.range(1..=5)),
+                4 => format!("{}{}", rng.choose(&['L', 'R']), rng.choose(&[90, 180, 270])),
+                _ => format!("F{}", rng.range(1..=100)),
+            };
+            instructions.push_str(&instruction);
+            instructions.push('\n');
+        }
+
+        instructions
+    }
+}
+
+// Compass moves as steps on the map.
+fn reference_step(action: Action) -> (i64, i64) {
+    match action {
+        Action::North => (0, 1),
+        Action::South => (0, -1),
+        Action::East => (1, 0),
+        Action::West => (-1, 0),
+        _ => (0, 0),
+    }
+}
+
+// Turns `(x, y)` left by `degrees`, a quarter turn at a time.
+fn reference_turn((x, y): (i64, i64), degrees: i32) -> (i64, i64) {
+    let quarters = degrees.rem_euclid(360) / 90;
+    (0..quarters).fold((x, y), |(x, y), _| (-y, x))
+}
+
+impl Reference for Day12 {
+    const REFERENCE_SIZE: usize = 300;
+
+    fn reference_part_one(instructions: &Self::Input) -> Option<Answer> {
+        let (mut position, mut heading) = ((0, 0), (1, 0));
+        for instruction in instructions {
+            let value = i64::from(instruction.value);
+            match instruction.action {
+                Action::Left => heading = reference_turn(heading, instruction.value),
+                Action::Right => heading = reference_turn(heading, -instruction.value),
+                Action::Forward => {
+                    position.0 += heading.0 * value;
+                    position.1 += heading.1 * value;
+                }
+                action => {
+                    let (dx, dy) = reference_step(action);
+                    position.0 += dx * value;
+                    position.1 += dy * value;
+                }
+            }
+        }
+
+        Some((position.0.abs() + position.1.abs()).into())
+    }
+
+    fn reference_part_two(instructions: &Self::Input) -> Option<Answer> {
+        let (mut position, mut waypoint) = ((0, 0), (10, 1));
+        for instruction in instructions {
+            let value = i64::from(instruction.value);
+            match instruction.action {
+                Action::Left => waypoint = reference_turn(waypoint, instruction.value),
+                Action::Right => waypoint = reference_turn(waypoint, -instruction.value),
+                Action::Forward => {
+                    position.0 += waypoint.0 * value;
+                    position.1 += waypoint.1 * value;
+                }
+                action => {
+                    let (dx, dy) = reference_step(action);
+                    waypoint.0 += dx * value;
+                    waypoint.1 += dy * value;
+                }
+            }
+        }
+
+        Some((position.0.abs() + position.1.abs()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::simulation;
+
+    const EXAMPLE: &str = "\
+F10
+N3
+F7
+R90
+F11
+";
+
+    #[test]
+    fn example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part_one(&input), Answer::Number(25));
+        assert_eq!(Day12::part_two(&input), Answer::Number(286));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day12::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day12::part_one(&input), Answer::Number(521));
+        assert_eq!(Day12::part_two(&input), Answer::Number(22848));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day12::parse(&Day12::generate(&mut Rng::new(seed), 100)).unwrap();
+            assert_eq!(input.len(), 100);
+        }
+        let input = Day12::parse(&Day12::generate(&mut Rng::new(0), 100_000)).unwrap();
+        part_two(&input);
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day12>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day12>(EXAMPLE);
+    }
+
+    #[test]
+    fn simulation() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        let one = simulation::run_to_end::<Day12>(&input, Part::One, 100);
+        let two = simulation::run_to_end::<Day12>(&input, Part::Two, 100);
+        assert_eq!((one, two), (Some(25), Some(286)));
+
+        let start = Day12::start(&input, Part::Two);
+        let first = Day12::step(&input, &start, Part::Two).unwrap();
+        assert_eq!(
+            Day12::render(&first),
+            "Instructions followed: 1\nShip:     east 100, north 10\nWaypoint: east 10, north 1\n"
+        );
+    }
+}
@@ -0,0 +1,404 @@
+//! Solution to [Day 13: Shuttle Search](https://adventofcode.com/2020/day/13).
+
+/*
+--- Day 13: Shuttle Search ---
+
+Your ferry can make it safely to a nearby port, but it won't get much further. When you call to book
+another ship, you discover that no ships embark from that port to your vacation island. You'll need
+to get from the port to the nearest airport.
+
+Fortunately, a shuttle bus service is available to bring you from the sea port to the airport! Each
+bus has an ID number that also indicates how often the bus leaves for the airport.
+
+Bus schedules are defined based on a timestamp that measures the number of minutes since some fixed
+reference point in the past. At timestamp 0, every bus simultaneously departed from the sea port.
+After that, each bus travels to the airport, then various other locations, and finally returns to
+the sea port to repeat its journey forever.
+
+The time this loop takes a particular bus is also its ID number: the bus with ID 5 departs from the
+sea port at timestamps 0, 5, 10, 15, and so on. The bus with ID 11 departs at 0, 11, 22, 33, and so
+on. If you are there when the bus departs, you can ride that bus to the airport!
+
+Your notes (your puzzle input) consist of two lines. The first line is your estimate of the earliest
+timestamp you could depart on a bus. The second line lists the bus IDs that are in service according
+to the shuttle company; entries that show x must be out of service, so you decide to ignore them.
+
+To save time once you arrive, your goal is to figure out the earliest bus you can take to the
+airport. (There will be exactly one such bus.)
+
+For example, suppose you have the following notes:
+
+939
+7,13,x,x,59,x,31,19
+
+Here, the earliest timestamp you could depart is 939, and the bus IDs in service are 7, 13, 59, 31,
+and 19. Near timestamp 939, these bus IDs depart at the times marked D:
+
+time   bus 7   bus 13  bus 59  bus 31  bus 19
+929      .       .       .       .       .
+930      .       .       .       D       .
+931      D       .       .       .       D
+932      .       .       .       .       .
+933      .       .       .       .       .
+934      .       .       .       .       .
+935      .       .       .       .       .
+936      .       D       .       .       .
+937      .       .       .       .       .
+938      D       .       .       .       .
+939      .       .       .       .       .
+940      .       .       .       .       .
+941      .       .       .       .       .
+942      .       .       .       .       .
+943      .       .       .       .       .
+944      .       .       D       .       .
+945      D       .       .       .       .
+946      .       .       .       .       .
+947      .       .       .       .       .
+948      .       .       .       .       .
+949      .       D       .       .       .
+
+The earliest bus you could take is bus ID 59. It doesn't depart until timestamp 944, so you would
+need to wait 944 - 939 = 5 minutes before it departs. Multiplying the bus ID by the number of
+minutes you'd need to wait gives 295.
+
+What is the ID of the earliest bus you can take to the airport multiplied by the number of minutes
+you'll need to wait for that bus?
+*/
+
+use common::input;
+use common::math;
+use common::parse::{self, ParseError, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::{debug, trace};
+use std::convert::TryFrom;
+
+/// The notes: your earliest possible departure and the buses in service.
+#[derive(Debug, Clone)]
+pub struct Notes {
+    pub estimate: i64,
+    pub buses: Vec<Bus>,
+}
+
+/// Parses the notes. Buses that are out of service (`x`) are left out, but still take up an
+/// offset.
+pub fn parse_input(input: &str) -> ParseResult<Notes> {
+    let input = &*input::normalize(input);
+    let mut list = input.lines();
+
+    let estimate = list
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a timestamp"))?;
+    let estimate = parse::value(input, estimate, "a timestamp")?;
+
+    let line = list
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a list of bus IDs"))?;
+    let mut buses = Vec::new();
+    for (i, id) in line.split(',').enumerate() {
+        if id == "x" {
+            continue;
+        }
+        match id.parse::<i64>() {
+            Ok(value) if value > 0 => buses.push(Bus {
+                id: value,
+                schedule_offset: i as i64,
+            }),
+            _ => return Err(ParseError::at(input, id, "a bus ID or `x`")),
+        }
+    }
+    if buses.is_empty() {
+        return Err(ParseError::at(input, line, "at least one bus ID"));
+    }
+
+    Ok(Notes { estimate, buses })
+}
+
+/// ID of the earliest bus you can take, multiplied by the number of minutes to wait for it.
+pub fn part_one(input: &Notes) -> i64 {
+    debug!("{:?}", input);
+    let mut id = 0;
+    let mut min_remainder = i64::MAX;
+    for bus_id in input.buses.iter().map(|bus| bus.id) {
+        let remainder = (bus_id - input.estimate % bus_id) % bus_id;
+        if remainder < min_remainder {
+            id = bus_id;
+            min_remainder = remainder;
+        }
+    }
+
+    debug!("Bus ID: {}", id);
+    debug!("Number of minutes to wait: {}", min_remainder);
+    id * min_remainder
+}
+
+/*
+--- Part Two ---
+
+The shuttle company is running a contest: one gold coin for anyone that can find the earliest
+timestamp such that the first bus ID departs at that time and each subsequent listed bus ID departs
+at that subsequent minute. (The first line in your input is no longer relevant.)
+
+For example, suppose you have the same list of bus IDs as above:
+
+7,13,x,x,59,x,31,19
+
+An x in the schedule means there are no constraints on what bus IDs must depart at that time.
+
+This means you are looking for the earliest timestamp (called t) such that:
+
+    Bus ID 7 departs at timestamp t.
+    Bus ID 13 departs one minute after timestamp t.
+    There are no requirements or restrictions on departures at two or three minutes after timestamp
+    t.
+    Bus ID 59 departs four minutes after timestamp t.
+    There are no requirements or restrictions on departures at five minutes after timestamp t.
+    Bus ID 31 departs six minutes after timestamp t.
+    Bus ID 19 departs seven minutes after timestamp t.
+
+The only bus departures that matter are the listed bus IDs at their specific offsets from t. Those
+bus IDs can depart at other times, and other bus IDs can depart at those times. For example, in the
+list above, because bus ID 19 must depart seven minutes after the timestamp at which bus ID 7
+departs, bus ID 7 will always also be departing with bus ID 19 at seven minutes after timestamp t.
+
+In this example, the earliest timestamp at which this occurs is 1068781:
+
+time     bus 7   bus 13  bus 59  bus 31  bus 19
+1068773    .       .       .       .       .
+1068774    D       .       .       .       .
+1068775    .       .       .       .       .
+1068776    .       .       .       .       .
+1068777    .       .       .       .       .
+1068778    .       .       .       .       .
+1068779    .       .       .       .       .
+1068780    .       .       .       .       .
+1068781    D       .       .       .       .
+1068782    .       D       .       .       .
+1068783    .       .       .       .       .
+1068784    .       .       .       .       .
+1068785    .       .       D       .       .
+1068786    .       .       .       .       .
+1068787    .       .       .       D       .
+1068788    D       .       .       .       D
+1068789    .       .       .       .       .
+1068790    .       .       .       .       .
+1068791    .       .       .       .       .
+1068792    .       .       .       .       .
+1068793    .       .       .       .       .
+1068794    .       .       .       .       .
+1068795    D       D       .       .       .
+1068796    .       .       .       .       .
+1068797    .       .       .       .       .
+
+In the above example, bus ID 7 departs at timestamp 1068788 (seven minutes after t). This is fine;
+the only requirement on that minute is that bus ID 19 departs then, and it does.
+
+Here are some other examples:
+
+    The earliest timestamp that matches the list 17,x,13,19 is 3417.
+    67,7,59,61 first occurs at timestamp 754018.
+    67,x,7,59,61 first occurs at timestamp 779210.
+    67,7,x,59,61 first occurs at timestamp 1261476.
+    1789,37,47,1889 first occurs at timestamp 1202161486.
+
+However, with so many bus IDs in your list, surely the actual earliest timestamp will be larger than
+100000000000000!
+
+What is the earliest timestamp such that all of the listed bus IDs depart at offsets matching their
+positions in the list?
+*/
+
+/// A bus in service, with its offset in the list of buses.
+#[derive(Debug, Clone)]
+pub struct Bus {
+    pub id: i64,
+    pub schedule_offset: i64,
+}
+
+/// Earliest timestamp at which every bus departs at its offset from that timestamp, or `None` if
+/// the buses never line up or the timestamp does not fit in an i64.
+pub fn part_two(notes: &Notes) -> Option<i64> {
+    debug!("{:?}", notes.buses);
+
+    // Bus `id` departs at `t + offset` when `t` is `-offset` modulo `id`
+    let congruences: Vec<_> = notes
+        .buses
+        .iter()
+        .map(|bus| (-i128::from(bus.schedule_offset), i128::from(bus.id)))
+        .collect();
+    let (t, period) = math::crt(&congruences)?;
+    trace!("t = {}, repeating every {}", t, period);
+
+    i64::try_from(t).ok()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Shuttle Search";
+
+    type Input = Notes;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day13 {
+    const SIZE: usize = 101;
+
+    // The size is the number of slots in the bus list. Bus IDs are distinct primes, so that
+    // part two has a solution, and their product is kept below 2^60 for it to fit in an `i64`.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut primes: Vec<i64> = (7..1000)
+            .filter(|n| (2..*n).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+            .collect();
+        rng.shuffle(&mut primes);
+        let mut primes = primes.into_iter();
+
+        let mut product: i64 = 1;
+        let mut ids = Vec::new();
+        let mut slots = Vec::new();
+        for i in 0..size.max(1) {
+            // The first slot is always a bus, as in the real input
+            let bus = if i == 0 || rng.chance(0.1) {
+                primes
+                    .next()
+                    .filter(|id| product.checked_mul(*id).is_some_and(|p| p < 1 << 60))
+            } else {
+                None
+            };
+            match bus {
+                Some(id) => {
+                    product *= id;
+                    ids.push(id);
+                    slots.push(id.to_string());
+                }
+                None => slots.push("x".to_owned()),
+            }
+        }
+
+        // Sometimes a bus leaves right at the estimate
+        let mut estimate = rng.range(100_000..=1_000_000);
+        if rng.chance(0.2) {
+            let id = ids[rng.below(ids.len())];
+            estimate -= estimate % id;
+        }
+
+        format!("{}\n{}\n", estimate, slots.join(","))
+    }
+}
+
+impl Reference for Day13 {
+    const REFERENCE_SIZE: usize = 30;
+
+    // Waits a minute at a time until a bus departs.
+    fn reference_part_one(notes: &Self::Input) -> Option<Answer> {
+        for t in notes.estimate.. {
+            if let Some(bus) = notes.buses.iter().find(|bus| t % bus.id == 0) {
+                return Some((bus.id * (t - notes.estimate)).into());
+            }
+        }
+
+        None
+    }
+
+    // Tries every departure of the first bus, as long as that takes no more than a few million
+    // tries.
+    fn reference_part_two(notes: &Self::Input) -> Option<Answer> {
+        let first = notes.buses[0].id;
+        let period: i64 = notes.buses.iter().map(|bus| bus.id).product();
+        if period / first > 5_000_000 {
+            return None;
+        }
+        let t = (0..period).step_by(first as usize).find(|t| {
+            notes
+                .buses
+                .iter()
+                .all(|bus| (t + bus.schedule_offset) % bus.id == 0)
+        });
+
+        Some(t.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+939
+7,13,x,x,59,x,31,19
+";
+
+    #[test]
+    fn example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part_one(&input), Answer::Number(295));
+        assert_eq!(Day13::part_two(&input), Answer::Number(1068781));
+    }
+
+    #[test]
+    fn bus_at_estimate() {
+        let input = Day13::parse("14\n7,13\n").unwrap();
+        assert_eq!(Day13::part_one(&input), Answer::Number(0));
+    }
+
+    #[test]
+    fn example_schedules() {
+        let schedules = [
+            ("17,x,13,19", 3417),
+            ("67,7,59,61", 754018),
+            ("67,x,7,59,61", 779210),
+            ("67,7,x,59,61", 1261476),
+            ("1789,37,47,1889", 1202161486),
+        ];
+        for (buses, timestamp) in schedules.iter() {
+            let input = Day13::parse(&format!("0\n{}\n", buses)).unwrap();
+            assert_eq!(Day13::part_two(&input), Answer::Number(*timestamp));
+        }
+    }
+
+    #[test]
+    fn buses_that_never_line_up() {
+        // Bus 2 leaves at even timestamps, bus 4 at multiples of 4: never one apart
+        let input = Day13::parse("939\n2,4\n").unwrap();
+        assert_eq!(Day13::part_two(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day13::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day13::part_one(&input), Answer::Number(2165));
+        assert_eq!(Day13::part_two(&input), Answer::Number(534035653563227));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day13::parse(&Day13::generate(&mut Rng::new(seed), 100)).unwrap();
+            let t = part_two(&input).unwrap();
+            for bus in &input.buses {
+                assert_eq!((t + bus.schedule_offset) % bus.id, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day13>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day13>(EXAMPLE);
+    }
+}
@@ -0,0 +1,471 @@
+//! Solution to [Day 14: Docking Data](https://adventofcode.com/2020/day/14).
+
+use common::input;
+use common::parse::{self, ParseError, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::trace;
+use std::collections::HashMap;
+
+/*
+--- Day 14: Docking Data ---
+
+As your ferry approaches the sea port, the captain asks for your help again. The computer system
+that runs this port isn't compatible with the docking program on the ferry, so the docking
+parameters aren't being correctly initialized in the docking program's memory.
+
+After a brief inspection, you discover that the sea port's computer system uses a strange bitmask
+system in its initialization program. Although you don't have the correct decoder chip handy, you
+can emulate it in software!
+
+The initialization program (your puzzle input) can either update the bitmask or write a value to
+memory. Values and memory addresses are both 36-bit unsigned integers. For example, ignoring
+bitmasks for a moment, a line like mem[8] = 11 would write the value 11 to memory address 8.
+
+The bitmask is always given as a string of 36 bits, written with the most significant bit
+(representing 2^35) on the left and the least significant bit (2^0, that is, the 1s bit) on the
+right. The current bitmask is applied to values immediately before they are written to memory:
+a 0 or 1 overwrites the corresponding bit in the value, while an X leaves the bit in the value
+unchanged.
+
+For example, consider the following program:
+
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0
+
+This program starts by specifying a bitmask (mask = ....). The mask it specifies will overwrite two
+bits in every written value: the 2s bit is overwritten with 0, and the 64s bit is overwritten
+with 1.
+
+The program then attempts to write the value 11 to memory address 8. By expanding everything out to
+individual bits, the mask is applied as follows:
+
+value:  000000000000000000000000000000001011  (decimal 11)
+mask:   XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+result: 000000000000000000000000000001001001  (decimal 73)
+
+So, because of the mask, the value 73 is written to memory address 8 instead. Then, the program
+tries to write 101 to address 7:
+
+value:  000000000000000000000000000001100101  (decimal 101)
+mask:   XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+result: 000000000000000000000000000001100101  (decimal 101)
+
+This time, the mask has no effect, as the bits it overwrote were already the values the mask tried
+to set. Finally, the program tries to write 0 to address 8:
+
+value:  000000000000000000000000000000000000  (decimal 0)
+mask:   XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+result: 000000000000000000000000000001000000  (decimal 64)
+
+64 is written to address 8 instead, overwriting the value that was there previously.
+
+To initialize your ferry's docking program, you need the sum of all values left in memory after the
+initialization program completes. (The entire 36-bit address space begins initialized to the value 0
+at every address.) In the above example, only two values in memory are not zero - 101 (at address 7)
+and 64 (at address 8) - producing a sum of 165.
+
+Execute the initialization program. What is the sum of all values left in memory after it completes?
+*/
+
+// A mask is stored as three bit sets: the bits that are 1, the bits that are 0,
+// and the bits that are X. Every one of the 36 bits is in exactly one of them.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Mask {
+        ones: u64,
+        zeros: u64,
+        floating: u64,
+    },
+    Memory {
+        address: u64,
+        value: u64,
+    },
+}
+
+/// Parses the initialization program, one operation per line.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Operation>> {
+    let input = &*input::normalize(input);
+    let mut operations = Vec::new();
+    for line in input.lines() {
+        let (left, right) = line
+            .split_once(" = ")
+            .ok_or_else(|| ParseError::at(input, line, "`mask = ...` or `mem[...] = ...`"))?;
+        if left == "mask" {
+            if right.len() != 36 {
+                return Err(ParseError::at(input, right, "a mask of 36 bits"));
+            }
+            let mut ones = 0;
+            let mut zeros = 0;
+            let mut floating = 0;
+            for (i, c) in right.char_indices() {
+                ones <<= 1;
+                zeros <<= 1;
+                floating <<= 1;
+                match c {
+                    '1' => ones += 1,
+                    '0' => zeros += 1,
+                    'X' => floating += 1,
+                    _ => {
+                        let token = &right[i..i + c.len_utf8()];
+                        return Err(ParseError::at(input, token, "`0`, `1` or `X`"));
+                    }
+                }
+            }
+            operations.push(Operation::Mask {
+                ones,
+                zeros,
+                floating,
+            });
+        } else {
+            let digits = left
+                .strip_prefix("mem[")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .ok_or_else(|| ParseError::at(input, left, "`mask` or `mem[address]`"))?;
+            let address: u64 = parse::value(input, digits, "a 36-bit memory address")?;
+            if address >= 1 << 36 {
+                return Err(ParseError::at(input, digits, "a 36-bit memory address"));
+            }
+            let value: u64 = parse::value(input, right, "a 36-bit value")?;
+            if value >= 1 << 36 {
+                return Err(ParseError::at(input, right, "a 36-bit value"));
+            }
+            operations.push(Operation::Memory { address, value });
+        }
+    }
+
+    Ok(operations)
+}
+
+/// Sum of all values in memory after running the program on a version 1 decoder chip, where the
+/// mask applies to values.
+pub fn part_one(operations: &[Operation]) -> u64 {
+    let mut and_mask = 0;
+    let mut or_mask = 0;
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+    for op in operations {
+        match *op {
+            Operation::Mask { ones, floating, .. } => {
+                and_mask = ones | floating; // Has ones at all other places (binary AND)
+                or_mask = ones; // Has zeroes at all other places (binary OR)
+            }
+            Operation::Memory { address, value } => {
+                let mut value = value;
+                value &= and_mask;
+                value |= or_mask;
+                memory.insert(address, value);
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for kv in memory {
+        sum += kv.1;
+    }
+
+    sum
+}
+
+/*
+--- Part Two ---
+
+For some reason, the sea port's computer system still can't communicate with your ferry's docking
+program. It must be using version 2 of the decoder chip!
+
+A version 2 decoder chip doesn't modify the values being written at all. Instead, it acts as a
+memory address decoder. Immediately before a value is written to memory, each bit in the bitmask
+modifies the corresponding bit of the destination memory address in the following way:
+
+    If the bitmask bit is 0, the corresponding memory address bit is unchanged.
+    If the bitmask bit is 1, the corresponding memory address bit is overwritten with 1.
+    If the bitmask bit is X, the corresponding memory address bit is floating.
+
+A floating bit is not connected to anything and instead fluctuates unpredictably. In practice, this
+means the floating bits will take on all possible values, potentially causing many memory addresses
+to be written all at once!
+
+For example, consider the following program:
+
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1
+
+When this program goes to write to memory address 42, it first applies the bitmask:
+
+address: 000000000000000000000000000000101010  (decimal 42)
+mask:    000000000000000000000000000000X1001X
+result:  000000000000000000000000000000X1101X
+
+After applying the mask, four bits are overwritten, three of which are different, and two of which
+are floating. Floating bits take on every possible combination of values; with two floating bits,
+four actual memory addresses are written:
+
+000000000000000000000000000000011010  (decimal 26)
+000000000000000000000000000000011011  (decimal 27)
+000000000000000000000000000000111010  (decimal 58)
+000000000000000000000000000000111011  (decimal 59)
+
+Next, the program is about to write to memory address 26 with a different bitmask:
+
+address: 000000000000000000000000000000011010  (decimal 26)
+mask:    00000000000000000000000000000000X0XX
+result:  00000000000000000000000000000001X0XX
+
+This results in an address with three floating bits, causing writes to eight memory addresses:
+
+000000000000000000000000000000010000  (decimal 16)
+000000000000000000000000000000010001  (decimal 17)
+000000000000000000000000000000010010  (decimal 18)
+000000000000000000000000000000010011  (decimal 19)
+000000000000000000000000000000011000  (decimal 24)
+000000000000000000000000000000011001  (decimal 25)
+000000000000000000000000000000011010  (decimal 26)
+000000000000000000000000000000011011  (decimal 27)
+
+The entire 36-bit address space still begins initialized to the value 0 at every address, and you
+still need the sum of all values left in memory at the end of the program. In this example, the sum
+is 208.
+
+Execute the initialization program using an emulator for a version 2 decoder chip. What is the sum
+of all values left in memory after it completes?
+*/
+
+/// Sum of all values in memory after running the program on a version 2 decoder chip, where the
+/// mask applies to addresses.
+pub fn part_two(operations: &[Operation]) -> u64 {
+    let mut or_mask = 0;
+    let mut and_mask = 0;
+    let mut floating_vec = vec![];
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+    for op in operations {
+        match *op {
+            Operation::Mask {
+                ones,
+                zeros,
+                floating,
+            } => {
+                or_mask = ones;
+                and_mask = ones | zeros;
+                floating_vec.clear();
+                for i in 0..36 {
+                    if (floating >> (35 - i)) & 1 > 0 {
+                        floating_vec.push(1 << (35 - i));
+                    }
+                }
+                trace!("Floating bits: {:036b}", floating);
+            }
+            Operation::Memory { address, value } => {
+                let size = floating_vec.len();
+                for i in 0..(1 << size) {
+                    let mut sum = (address & and_mask) | or_mask;
+                    for (j, bit) in floating_vec.iter().enumerate() {
+                        if ((i >> j) & 1) > 0 {
+                            sum += bit;
+                        }
+                    }
+                    memory.insert(sum, value);
+                }
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for kv in memory {
+        sum += kv.1;
+    }
+
+    sum
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Docking Data";
+
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day14 {
+    const SIZE: usize = 549;
+
+    // The size is the number of lines. Like in the real input, masks have at most nine `X`s,
+    // because part two writes to two to the power of that number of addresses.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut program = String::new();
+        let mut writes = 0;
+        for _ in 0..size.max(1) {
+            if writes == 0 {
+                let mut mask: Vec<char> = (0..36).map(|_| *rng.choose(&['0', '1'])).collect();
+                for _ in 0..rng.range(0..=9) {
+                    mask[rng.below(36)] = 'X';
+                }
+                program.push_str(&format!(
+                    "mask = {}\n",
+                    mask.into_iter().collect::<String>()
+                ));
+                writes = rng.range(1..=6);
+            } else {
+                let address = rng.range(0..=0xffff);
+                let value = rng.range(0..=(1 << 36) - 1);
+                program.push_str(&format!("mem[{}] = {}\n", address, value));
+                writes -= 1;
+            }
+        }
+
+        program
+    }
+}
+
+// The mask of a `Mask` operation as text again, from the highest bit to the lowest.
+fn reference_mask(ones: u64, floating: u64) -> Vec<char> {
+    (0..36)
+        .rev()
+        .map(|bit| match ((ones >> bit) & 1, (floating >> bit) & 1) {
+            (1, _) => '1',
+            (_, 1) => 'X',
+            _ => '0',
+        })
+        .collect()
+}
+
+// Goes through the program bit by bit. `write` gives the memory writes for an address, a value
+// and the mask.
+fn reference_run<F>(operations: &[Operation], write: F) -> u64
+where
+    F: Fn(u64, u64, &[char]) -> Vec<(u64, u64)>,
+{
+    let mut mask = vec!['X'; 36];
+    let mut memory = std::collections::BTreeMap::new();
+    for operation in operations {
+        match *operation {
+            Operation::Mask { ones, floating, .. } => mask = reference_mask(ones, floating),
+            Operation::Memory { address, value } => memory.extend(write(address, value, &mask)),
+        }
+    }
+
+    memory.values().sum()
+}
+
+impl Reference for Day14 {
+    const REFERENCE_SIZE: usize = 200;
+
+    fn reference_part_one(operations: &Self::Input) -> Option<Answer> {
+        let sum = reference_run(operations, |address, value, mask| {
+            let mut masked = 0;
+            for (i, &m) in mask.iter().enumerate() {
+                let bit = match m {
+                    '1' => 1,
+                    '0' => 0,
+                    _ => (value >> (35 - i)) & 1,
+                };
+                masked = masked << 1 | bit;
+            }
+            vec![(address, masked)]
+        });
+
+        Some(sum.into())
+    }
+
+    fn reference_part_two(operations: &Self::Input) -> Option<Answer> {
+        let sum = reference_run(operations, |address, value, mask| {
+            // Every `X` doubles the addresses written so far
+            let mut addresses = vec![0];
+            for (i, &m) in mask.iter().enumerate() {
+                let bits: &[u64] = match m {
+                    '1' => &[1],
+                    '0' => &[(address >> (35 - i)) & 1],
+                    _ => &[0, 1],
+                };
+                addresses = addresses
+                    .iter()
+                    .flat_map(|a| bits.iter().map(move |bit| a << 1 | bit))
+                    .collect();
+            }
+            addresses.into_iter().map(|a| (a, value)).collect()
+        });
+
+        Some(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0
+";
+    const EXAMPLE_TWO: &str = "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1
+";
+
+    #[test]
+    fn example_one() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part_one(&input), Answer::Number(165));
+    }
+
+    #[test]
+    fn example_two() {
+        let input = Day14::parse(EXAMPLE_TWO).unwrap();
+        assert_eq!(Day14::part_two(&input), Answer::Number(208));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day14::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day14::part_one(&input), Answer::Number(8471403462063));
+        assert_eq!(Day14::part_two(&input), Answer::Number(2667858637669));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day14::parse(&Day14::generate(&mut Rng::new(seed), 100)).unwrap();
+            assert_eq!(input.len(), 100);
+            assert!(matches!(input[0], Operation::Mask { .. }));
+            part_two(&input);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day14>();
+    }
+
+    #[test]
+    fn wider_than_36_bits() {
+        let mask = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\n";
+        let error = Day14::parse(&format!("{}mem[8] = 68719476736\n", mask)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+        let error = Day14::parse(&format!("{}mem[68719476736] = 11\n", mask)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert!(Day14::parse(&format!("{}mem[68719476735] = 68719476735\n", mask)).is_ok());
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day14>(EXAMPLE_TWO);
+    }
+}
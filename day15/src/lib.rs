@@ -0,0 +1,251 @@
+//! Solution to [Day 15: Rambunctious Recitation](https://adventofcode.com/2020/day/15).
+
+use common::input;
+use common::parse::{self, ParseError, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::trace;
+use std::collections::HashMap;
+
+/*
+--- Day 15: Rambunctious Recitation ---
+
+You catch the airport shuttle and try to book a new flight to your vacation island. Due to the
+storm, all direct flights have been cancelled, but a route is available to get around the storm.
+You take it.
+
+While you wait for your flight, you decide to check in with the Elves back at the North Pole.
+They're playing a memory game and are ever so excited to explain the rules!
+
+In this game, the players take turns saying numbers. They begin by taking turns reading from a list
+of starting numbers (your puzzle input). Then, each turn consists of considering the most recently
+spoken number:
+
+    If that was the first time the number has been spoken, the current player says 0.
+    Otherwise, the number had been spoken before; the current player announces how many turns apart
+        the number is from when it was previously spoken.
+
+So, after the starting numbers, each turn results in that player speaking aloud either 0
+(if the last number is new) or an age (if the last number is a repeat).
+
+For example, suppose the starting numbers are 0,3,6:
+
+    Turn 1: The 1st number spoken is a starting number, 0.
+    Turn 2: The 2nd number spoken is a starting number, 3.
+    Turn 3: The 3rd number spoken is a starting number, 6.
+    Turn 4: Now, consider the last number spoken, 6. Since that was the first time the number had
+        been spoken, the 4th number spoken is 0.
+    Turn 5: Next, again consider the last number spoken, 0. Since it had been spoken before, the
+        next number to speak is the difference between the turn number when it was last spoken
+        (the previous turn, 4) and the turn number of the time it was most recently spoken before
+        then (turn 1). Thus, the 5th number spoken is 4 - 1, 3.
+    Turn 6: The last number spoken, 3 had also been spoken before, most recently on turns 5 and 2.
+        So, the 6th number spoken is 5 - 2, 3.
+    Turn 7: Since 3 was just spoken twice in a row, and the last two turns are 1 turn apart,
+        the 7th number spoken is 1.
+    Turn 8: Since 1 is new, the 8th number spoken is 0.
+    Turn 9: 0 was last spoken on turns 8 and 4, so the 9th number spoken is the difference
+        between them, 4.
+    Turn 10: 4 is new, so the 10th number spoken is 0.
+
+(The game ends when the Elves get sick of playing or dinner is ready, whichever comes first.)
+
+Their question for you is: what will be the 2020th number spoken? In the example above, the 2020th
+number spoken will be 436.
+
+Here are a few more examples:
+
+    Given the starting numbers 1,3,2, the 2020th number spoken is 1.
+    Given the starting numbers 2,1,3, the 2020th number spoken is 10.
+    Given the starting numbers 1,2,3, the 2020th number spoken is 27.
+    Given the starting numbers 2,3,1, the 2020th number spoken is 78.
+    Given the starting numbers 3,2,1, the 2020th number spoken is 438.
+    Given the starting numbers 3,1,2, the 2020th number spoken is 1836.
+
+Given your starting numbers, what will be the 2020th number spoken?
+*/
+
+/// Parses the comma-separated starting numbers.
+pub fn parse_input(input: &str) -> ParseResult<Vec<i32>> {
+    let input = &*input::normalize(input);
+    let line = match input.lines().next() {
+        Some(line) if !line.is_empty() => line,
+        _ => {
+            return Err(ParseError::end_of_input(
+                input,
+                "a list of starting numbers",
+            ))
+        }
+    };
+
+    line.split(',')
+        .map(|x| parse::value(input, x, "a starting number"))
+        .collect()
+}
+
+/// The `n`th number spoken in the memory game that begins with `list`.
+pub fn solve(list: &[i32], n: usize) -> i32 {
+    trace!("{:?}", list);
+    let mut timestamps: HashMap<i32, i32> = HashMap::new();
+    let mut t = 0;
+    for &i in list.iter().take(list.len() - 1) {
+        timestamps.insert(i, t);
+        t += 1;
+    }
+    let mut last_number_spoken = *list.last().unwrap();
+    for _ in 0..=n - list.len() - 1 {
+        match &timestamps.get(&last_number_spoken) {
+            Some(&prev) => {
+                timestamps.insert(last_number_spoken, t);
+                last_number_spoken = t - prev;
+            }
+            None => {
+                timestamps.insert(last_number_spoken, t);
+                last_number_spoken = 0;
+            }
+        }
+        t += 1;
+    }
+    last_number_spoken
+}
+
+/// The 2020th number spoken.
+pub fn part_one(input: &[i32]) -> i32 {
+    solve(input, 2020)
+}
+
+/*
+--- Part Two ---
+
+Impressed, the Elves issue you a challenge: determine the 30000000th number spoken. For example,
+given the same starting numbers as above:
+
+    Given 0,3,6, the 30000000th number spoken is 175594.
+    Given 1,3,2, the 30000000th number spoken is 2578.
+    Given 2,1,3, the 30000000th number spoken is 3544142.
+    Given 1,2,3, the 30000000th number spoken is 261214.
+    Given 2,3,1, the 30000000th number spoken is 6895259.
+    Given 3,2,1, the 30000000th number spoken is 18.
+    Given 3,1,2, the 30000000th number spoken is 362.
+
+Given your starting numbers, what will be the 30000000th number spoken?
+*/
+
+/// The 30000000th number spoken.
+pub fn part_two(input: &[i32]) -> i32 {
+    solve(input, 30000000)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Rambunctious Recitation";
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day15 {
+    const SIZE: usize = 6;
+
+    // The size is the number of starting numbers, which are all different.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut numbers: Vec<usize> = (0..3 * size).collect();
+        rng.shuffle(&mut numbers);
+        let numbers: Vec<String> = numbers[..size].iter().map(|n| n.to_string()).collect();
+
+        numbers.join(",") + "\n"
+    }
+}
+
+// Looks back through everything said so far for the number said last.
+fn reference_play(starting: &[i32], turns: usize) -> i32 {
+    let mut spoken = starting.to_vec();
+    while spoken.len() < turns {
+        let (last, before) = spoken.split_last().unwrap();
+        let next = match before.iter().rposition(|n| n == last) {
+            Some(i) => (before.len() - i) as i32,
+            None => 0,
+        };
+        spoken.push(next);
+    }
+
+    spoken[turns - 1]
+}
+
+// Part two plays thirty million turns, far too many to look back through every time.
+impl Reference for Day15 {
+    const REFERENCE_SIZE: usize = 20;
+
+    fn reference_part_one(starting: &Self::Input) -> Option<Answer> {
+        Some(reference_play(starting, 2020).into())
+    }
+
+    fn reference_part_two(_starting: &Self::Input) -> Option<Answer> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_one() {
+        let games = [
+            (vec![0, 3, 6], 436),
+            (vec![1, 3, 2], 1),
+            (vec![2, 1, 3], 10),
+            (vec![1, 2, 3], 27),
+            (vec![2, 3, 1], 78),
+            (vec![3, 2, 1], 438),
+            (vec![3, 1, 2], 1836),
+        ];
+        for (starting_numbers, number) in games.iter() {
+            assert_eq!(Day15::part_one(starting_numbers), Answer::Number(*number));
+        }
+    }
+
+    #[test]
+    fn example_part_two() {
+        assert_eq!(Day15::part_two(&vec![0, 3, 6]), Answer::Number(175594));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day15::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day15::part_one(&input), Answer::Number(257));
+        assert_eq!(Day15::part_two(&input), Answer::Number(8546398));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day15::parse(&Day15::generate(&mut Rng::new(seed), 6)).unwrap();
+            assert_eq!(input.len(), 6);
+            assert!(part_one(&input) >= 0);
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day15>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day15>("0,3,6\n");
+    }
+}
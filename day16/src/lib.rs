@@ -0,0 +1,644 @@
+//! Solution to [Day 16: Ticket Translation](https://adventofcode.com/2020/day/16).
+
+use common::input;
+use common::parse::{self, ParseError, ParseResult};
+use common::{Answer, Generate, Reference, Rng, Solution};
+use log::{debug, trace};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/*
+--- Day 16: Ticket Translation ---
+
+As you're walking to yet another connecting flight, you realize that one of the legs of your
+re-routed trip coming up is on a high-speed train. However, the train ticket you were given is in a
+language you don't understand. You should probably figure out what it says before you get to the
+train station after the next flight.
+
+Unfortunately, you can't actually read the words on the ticket. You can, however, read the numbers,
+and so you figure out the fields these tickets must have and the valid ranges for values in those
+fields.
+
+You collect the rules for ticket fields, the numbers on your ticket, and the numbers on other nearby
+tickets for the same train service (via the airport security cameras) together into a single
+document you can reference (your puzzle input).
+
+The rules for ticket fields specify a list of fields that exist somewhere on the ticket and the
+valid ranges of values for each field. For example, a rule like class: 1-3 or 5-7 means that one of
+the fields in every ticket is named class and can be any value in the ranges 1-3 or 5-7 (inclusive,
+    such that 3 and 5 are both valid in this field, but 4 is not).
+
+Each ticket is represented by a single line of comma-separated values. The values are the numbers on
+the ticket in the order they appear; every ticket has the same format. For example, consider this
+ticket:
+
+.--------------------------------------------------------.
+| ????: 101    ?????: 102   ??????????: 103     ???: 104 |
+|                                                        |
+| ??: 301  ??: 302             ???????: 303      ??????? |
+| ??: 401  ??: 402           ???? ????: 403    ????????? |
+'--------------------------------------------------------'
+
+Here, ? represents text in a language you don't understand. This ticket might be represented as
+101,102,103,104,301,302,303,401,402,403; of course, the actual train tickets you're looking at are
+much more complicated. In any case, you've extracted just the numbers in such a way that the first
+number is always the same specific field, the second number is always a different specific field,
+and so on - you just don't know what each position actually means!
+
+Start by determining which tickets are completely invalid; these are tickets that contain values
+which aren't valid for any field. Ignore your ticket for now.
+
+For example, suppose you have the following notes:
+
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+
+It doesn't matter which position corresponds to which field; you can identify invalid nearby tickets
+by considering only whether tickets contain values that are not valid for any field. In this
+example, the values on the first nearby ticket are all valid for at least one field. This is not
+true of the other three nearby tickets: the values 4, 55, and 12 are are not valid for any field.
+Adding together all of the invalid values produces your ticket scanning error rate:
+4 + 55 + 12 = 71.
+
+Consider the validity of the nearby tickets you scanned. What is your ticket scanning error rate?
+*/
+
+/// An inclusive range of valid values.
+#[derive(Debug, Clone)]
+pub struct Range {
+    pub min: i32, // inclusive
+    pub max: i32, // inclusive
+}
+
+/// A ticket field, with the two ranges of values that are valid for it.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub name: String,
+    pub range_first: Range,
+    pub range_second: Range,
+}
+
+/// The values on a ticket, in the order they appear.
+#[derive(Debug, Clone)]
+pub struct Ticket {
+    pub values: Vec<i32>,
+}
+
+/// Everything in the notes.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub rules: Vec<Rule>,
+    pub ticket_mine: Ticket,
+    pub tickets_nearby: Vec<Ticket>,
+}
+
+/// Parses a range such as `1-3`. `string` must be a slice of `input`.
+pub fn extract_range(input: &str, string: &str) -> ParseResult<Range> {
+    let (min, max) = string
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(input, string, "a range like `1-3`"))?;
+
+    Ok(Range {
+        min: parse::value(input, min, "a number")?,
+        max: parse::value(input, max, "a number")?,
+    })
+}
+
+/// Parses the comma-separated values of a ticket. `string` must be a slice of `input`.
+pub fn parse_ticket(input: &str, string: &str) -> ParseResult<Ticket> {
+    Ok(Ticket {
+        values: string
+            .split(',')
+            .map(|x| parse::value(input, x, "a ticket value"))
+            .collect::<ParseResult<_>>()?,
+    })
+}
+
+/// Parses the notes: the rules, your ticket and the nearby tickets.
+pub fn parse_input(input: &str) -> ParseResult<Input> {
+    let input = &*input::normalize(input);
+    let mut rules: Vec<Rule> = Vec::new();
+    let mut tickets_nearby: Vec<Ticket> = Vec::new();
+
+    let input_lines: Vec<_> = input
+        .lines()
+        .filter(|x| !x.is_empty() && !x.contains("nearby tickets:"))
+        .collect();
+
+    // rules
+    let mut it = input_lines.iter();
+    loop {
+        let line = it
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "`your ticket:`"))?;
+        trace!("{:?}", line);
+        if line.contains("your ticket:") {
+            break;
+        }
+
+        let (name, ranges) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(input, line, "a rule like `class: 1-3 or 5-7`"))?;
+        let (first, second) = ranges
+            .split_once(" or ")
+            .ok_or_else(|| ParseError::at(input, ranges, "two ranges separated by `or`"))?;
+        let range_first = extract_range(input, first)?;
+        let range_second = extract_range(input, second)?;
+
+        rules.push(Rule {
+            name: name.to_owned(),
+            range_first,
+            range_second,
+        })
+    }
+    trace!("{:?}", rules);
+    // ticket_mine
+    let line = it
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "your ticket"))?;
+    let ticket_mine = parse_ticket(input, line)?;
+    // Every field needs a rule, and every rule a field
+    if ticket_mine.values.len() != rules.len() {
+        return Err(ParseError::at(
+            input,
+            line,
+            format!("a ticket with {} values", rules.len()),
+        ));
+    }
+
+    // tickets_nearby
+    for line in it {
+        let ticket = parse_ticket(input, line)?;
+        if ticket.values.len() != rules.len() {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a ticket with {} values", rules.len()),
+            ));
+        }
+        tickets_nearby.push(ticket)
+    }
+
+    Ok(Input {
+        rules,
+        ticket_mine,
+        tickets_nearby,
+    })
+}
+
+/// Ticket scanning error rate: the sum of all nearby ticket values that match no rule.
+pub fn part_one(input: &Input) -> i32 {
+    trace!("{:?}", input);
+    let mut sum = 0;
+    for ticket in &input.tickets_nearby {
+        sum += ticket.values.iter().fold(0, |acc, x| {
+            let mut has_rule = false;
+            for rule in &input.rules {
+                if (x >= &rule.range_first.min && x <= &rule.range_first.max)
+                    || (x >= &rule.range_second.min && x <= &rule.range_second.max)
+                {
+                    has_rule = true;
+                    break;
+                }
+            }
+            if !has_rule {
+                return acc + *x;
+            }
+            acc
+        });
+    }
+
+    sum
+}
+
+/*
+--- Part Two ---
+
+Now that you've identified which tickets contain invalid values, discard those tickets entirely.
+Use the remaining valid tickets to determine which field is which.
+
+Using the valid ranges for each field, determine what order the fields appear on the tickets. The
+order is consistent between all tickets: if seat is the third field, it is the third field on every
+ticket, including your ticket.
+
+For example, suppose you have the following notes:
+
+class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9
+
+Based on the nearby tickets in the above example, the first position must be row, the second
+position must be class, and the third position must be seat; you can conclude that in your ticket,
+class is 12, row is 11, and seat is 13.
+
+Once you work out which field is which, look for the six fields on your ticket that start with the
+word departure. What do you get if you multiply those six values together?
+*/
+
+/// Whether `x` lies in either range of `rule`.
+pub fn rule_applies(rule: &Rule, x: i32) -> bool {
+    (x >= rule.range_first.min && x <= rule.range_first.max)
+        || (x >= rule.range_second.min && x <= rule.range_second.max)
+}
+
+fn rule_applies_list(rule: &Rule, list: &[i32]) -> bool {
+    for value in list {
+        if !rule_applies(rule, *value) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether `ticket` has a value that matches none of the `rules`.
+pub fn ticket_contains_invalid_values(ticket: &Ticket, rules: &[Rule]) -> bool {
+    for x in &ticket.values {
+        let mut has_rule = false;
+        for rule in rules {
+            if rule_applies(rule, *x) {
+                has_rule = true;
+                break;
+            }
+        }
+        if !has_rule {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Product of the values on your ticket of the fields that start with "departure", or `None` if
+/// the tickets do not pin down which field is which.
+pub fn part_two(input: &Input) -> Option<u64> {
+    // Filter
+    let mut filtered_nearby_tickets: Vec<_> = input
+        .tickets_nearby
+        .iter()
+        .filter(|ticket| !ticket_contains_invalid_values(ticket, &input.rules))
+        .collect();
+    filtered_nearby_tickets.push(&input.ticket_mine);
+
+    // Transpose
+    let len = input.ticket_mine.values.len();
+    let mut matrix_transposed: Vec<Vec<i32>> = vec![];
+    for i in 0..len {
+        matrix_transposed.push(
+            filtered_nearby_tickets
+                .iter()
+                .map(|x| x.values[i])
+                .collect(),
+        );
+    }
+    trace!("{:?}", matrix_transposed[0]);
+    let mut rule_set = HashSet::<usize>::new();
+    let mut working_set = HashSet::<usize>::new();
+    let mut ticket_translation = HashMap::<&str, i32>::new();
+    for i in 0..len {
+        rule_set.insert(i);
+        working_set.insert(i);
+    }
+    loop {
+        trace!("{:?}", rule_set);
+        trace!("{:?}", working_set);
+        let mut occurrences = HashMap::<usize, Vec<usize>>::new();
+        for i in &rule_set {
+            occurrences.insert(*i, vec![]);
+        }
+        for rule_index in &rule_set {
+            let rule = &input.rules[*rule_index];
+            for list_index in &working_set {
+                if rule_applies_list(rule, &matrix_transposed[*list_index]) {
+                    occurrences.get_mut(rule_index).unwrap().push(*list_index);
+                }
+            }
+        }
+
+        // Check which rules have one unique assignment
+        let mut assigned = false;
+        for kv in occurrences {
+            let rule_index = &kv.0;
+            let occurrence_list = &kv.1;
+            if occurrence_list.len() == 1 && working_set.remove(&occurrence_list[0]) {
+                let list_index = occurrence_list.first().unwrap();
+                trace!(
+                    "List {} = Rule {}: {}",
+                    list_index,
+                    rule_index,
+                    input.rules[*rule_index].name
+                );
+                ticket_translation.insert(
+                    &input.rules[*rule_index].name,
+                    input.ticket_mine.values[*list_index],
+                );
+                rule_set.remove(rule_index);
+                assigned = true;
+            }
+        }
+
+        if rule_set.is_empty() {
+            break;
+        }
+        // Every rule left fits no field or several, so there is more than one way to read them
+        if !assigned {
+            debug!("No unique field for rules {:?}", rule_set);
+            return None;
+        }
+    }
+    debug!("{:?}", ticket_translation);
+    Some(
+        ticket_translation
+            .iter()
+            .filter(|x| x.0.starts_with("departure"))
+            .map(|x| *x.1 as u64)
+            .product::<u64>(),
+    )
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Ticket Translation";
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+const FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+impl Generate for Day16 {
+    const SIZE: usize = 242;
+
+    // The size is the number of nearby tickets. The rules are ranked: the rule of rank `k` takes
+    // values up to `100 + 10 * k`, and your ticket has a value above the limit of the rank below
+    // in that rule's field. Then the rule of rank 0 fits one field only, the rule of rank 1 one
+    // of the fields that are left, and so on, so the fields can be worked out one at a time.
+    // Invalid values are between the highest limit and the second ranges.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let limit = |rank: usize| 100 + 10 * rank as i64;
+        let mut ranks: Vec<usize> = (0..FIELDS.len()).collect();
+        rng.shuffle(&mut ranks);
+        let mut columns: Vec<usize> = (0..FIELDS.len()).collect();
+        rng.shuffle(&mut columns);
+
+        let mut notes = String::new();
+        for (name, rank) in FIELDS.iter().zip(&ranks) {
+            notes.push_str(&format!("{}: 1-{} or 900-950\n", name, limit(*rank)));
+        }
+
+        let mine: Vec<String> = columns
+            .iter()
+            .map(|&field| {
+                let rank = ranks[field];
+                let above = if rank == 0 { 1 } else { limit(rank - 1) + 1 };
+                rng.range(above..=limit(rank)).to_string()
+            })
+            .collect();
+        notes.push_str(&format!(
+            "\nyour ticket:\n{}\n\nnearby tickets:\n",
+            mine.join(",")
+        ));
+
+        for _ in 0..size {
+            let mut values: Vec<i64> = columns
+                .iter()
+                .map(|&field| rng.range(1..=limit(ranks[field])))
+                .collect();
+            if rng.chance(0.25) {
+                let invalid = if rng.chance(0.8) {
+                    rng.range(limit(FIELDS.len())..=899)
+                } else {
+                    rng.range(951..=999)
+                };
+                values[rng.below(FIELDS.len())] = invalid;
+            }
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            notes.push_str(&values.join(","));
+            notes.push('\n');
+        }
+
+        notes
+    }
+}
+
+// Gives every field a rule, given which rules `fit` each field, and adds each way to do so to
+// `found` until there are two. Tries the field with the fewest rules left first, and backs up
+// when some field is left without one.
+fn reference_assign(
+    fit: &[Vec<bool>],
+    rules: &mut Vec<Option<usize>>,
+    used: &mut Vec<bool>,
+    found: &mut Vec<Vec<Option<usize>>>,
+) {
+    let left = |field: usize| {
+        (0..used.len())
+            .filter(|&r| fit[field][r] && !used[r])
+            .count()
+    };
+    let field = match (0..fit.len())
+        .filter(|&f| rules[f].is_none())
+        .min_by_key(|&f| left(f))
+    {
+        Some(field) => field,
+        None => {
+            found.push(rules.clone());
+            return;
+        }
+    };
+    for rule in 0..used.len() {
+        if fit[field][rule] && !used[rule] {
+            rules[field] = Some(rule);
+            used[rule] = true;
+            reference_assign(fit, rules, used, found);
+            rules[field] = None;
+            used[rule] = false;
+            if found.len() >= 2 {
+                return;
+            }
+        }
+    }
+}
+
+impl Reference for Day16 {
+    const REFERENCE_SIZE: usize = 100;
+
+    fn reference_part_one(input: &Self::Input) -> Option<Answer> {
+        let sum: i32 = input
+            .tickets_nearby
+            .iter()
+            .flat_map(|ticket| &ticket.values)
+            .filter(|&&value| !input.rules.iter().any(|rule| rule_applies(rule, value)))
+            .sum();
+
+        Some(sum.into())
+    }
+
+    fn reference_part_two(input: &Self::Input) -> Option<Answer> {
+        let mut valid: Vec<&Ticket> = input
+            .tickets_nearby
+            .iter()
+            .filter(|ticket| {
+                ticket
+                    .values
+                    .iter()
+                    .all(|&value| input.rules.iter().any(|rule| rule_applies(rule, value)))
+            })
+            .collect();
+        valid.push(&input.ticket_mine);
+
+        let fields = input.ticket_mine.values.len();
+        let fit: Vec<Vec<bool>> = (0..fields)
+            .map(|field| {
+                let values = valid.iter().map(|ticket| ticket.values[field]);
+                input
+                    .rules
+                    .iter()
+                    .map(|rule| values.clone().all(|value| rule_applies(rule, value)))
+                    .collect()
+            })
+            .collect();
+        let mut found = Vec::new();
+        let mut used = vec![false; input.rules.len()];
+        reference_assign(&fit, &mut vec![None; fields], &mut used, &mut found);
+        // The fields have to be told apart in exactly one way
+        if found.len() != 1 {
+            return Some(Answer::Unsolved);
+        }
+        let product: u64 = found[0]
+            .iter()
+            .zip(&input.ticket_mine.values)
+            .filter(|&(rule, _)| input.rules[rule.unwrap()].name.starts_with("departure"))
+            .map(|(_, &value)| value as u64)
+            .product();
+
+        Some(product.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+";
+
+    #[test]
+    fn example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part_one(&input), Answer::Number(71));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day16::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day16::part_one(&input), Answer::Number(26980));
+        assert_eq!(Day16::part_two(&input), Answer::Number(3021381607403));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let text = Day16::generate(&mut Rng::new(seed), 50);
+            let input = Day16::parse(&text).unwrap();
+            assert_eq!(input.tickets_nearby.len(), 50);
+            // Each value on your ticket is in the top 10 of what its rule takes
+            let expected: u64 = input.rules[..6]
+                .iter()
+                .map(|rule| {
+                    let max = rule.range_first.max;
+                    let min = if max == 100 { 1 } else { max - 9 };
+                    let values = &input.ticket_mine.values;
+                    *values.iter().find(|v| (min..=max).contains(v)).unwrap() as u64
+                })
+                .product();
+            assert_eq!(part_two(&input), Some(expected));
+        }
+    }
+
+    #[test]
+    fn ambiguous() {
+        // Without the nearby ticket on line 44, the real notes no longer tell every field apart
+        let notes: String = include_str!("../input")
+            .lines()
+            .enumerate()
+            .filter(|&(i, _)| i != 43)
+            .map(|(_, line)| format!("{}\n", line))
+            .collect();
+        let input = Day16::parse(&notes).unwrap();
+        assert_eq!(Day16::part_two(&input), Answer::Unsolved);
+        assert_eq!(Day16::reference_part_two(&input), Some(Answer::Unsolved));
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day16>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day16>(EXAMPLE);
+    }
+}
@@ -0,0 +1,869 @@
+//! Solution to [Day 17: Conway Cubes](https://adventofcode.com/2020/day/17).
+
+use common::input;
+use common::simulation::Simulation;
+use common::{Answer, Generate, Grid, ParseResult, Part, Reference, Rng, Solution, Vec3, Vec4};
+use log::trace;
+use std::collections::HashSet;
+use std::fmt;
+
+/*
+--- Day 17: Conway Cubes ---
+
+As your flight slowly drifts through the sky, the Elves at the Mythical Information Bureau at the
+North Pole contact you. They'd like some help debugging a malfunctioning experimental energy source
+aboard one of their super-secret imaging satellites.
+
+The experimental energy source is based on cutting-edge technology: a set of Conway Cubes contained
+in a pocket dimension! When you hear it's having problems, you can't help but agree to take a look.
+
+The pocket dimension contains an infinite 3-dimensional grid. At every integer 3-dimensional
+coordinate (x,y,z), there exists a single cube which is either active or inactive.
+
+In the initial state of the pocket dimension, almost all cubes start inactive. The only exception to
+this is a small flat region of cubes (your puzzle input); the cubes in this region start in the
+specified active (#) or inactive (.) state.
+
+The energy source then proceeds to boot up by executing six cycles.
+
+Each cube only ever considers its neighbors: any of the 26 other cubes where any of their
+coordinates differ by at most 1. For example, given the cube at x=1,y=2,z=3, its neighbors include
+the cube at x=2,y=2,z=2, the cube at x=0,y=2,z=3, and so on.
+
+During a cycle, all cubes simultaneously change their state according to the following rules:
+
+    If a cube is active and exactly 2 or 3 of its neighbors are also active, the cube remains
+        active. Otherwise, the cube becomes inactive.
+    If a cube is inactive but exactly 3 of its neighbors are active, the cube becomes active.
+        Otherwise, the cube remains inactive.
+
+The engineers responsible for this experimental energy source would like you to simulate the pocket
+dimension and determine what the configuration of cubes should be at the end of the six-cycle boot
+process.
+
+For example, consider the following initial state:
+
+.#.
+..#
+###
+
+Even though the pocket dimension is 3-dimensional, this initial state represents a small
+2-dimensional slice of it. (In particular, this initial state defines a 3x3x1 region of the
+3-dimensional space.)
+
+Simulating a few cycles from this initial state produces the following configurations, where the
+result of each cycle is shown layer-by-layer at each given z coordinate (and the frame of view
+follows the active cells in each cycle):
+
+Before any cycles:
+
+z=0
+.#.
+..#
+###
+
+
+After 1 cycle:
+
+z=-1
+#..
+..#
+.#.
+
+z=0
+#.#
+.##
+.#.
+
+z=1
+#..
+..#
+.#.
+
+
+After 2 cycles:
+
+z=-2
+.....
+.....
+..#..
+.....
+.....
+
+z=-1
+..#..
+.#..#
+....#
+.#...
+.....
+
+z=0
+##...
+##...
+#....
+....#
+.###.
+
+z=1
+..#..
+.#..#
+....#
+.#...
+.....
+
+z=2
+.....
+.....
+..#..
+.....
+.....
+
+
+After 3 cycles:
+
+z=-2
+.......
+.......
+..##...
+..###..
+.......
+.......
+.......
+
+z=-1
+..#....
+...#...
+#......
+.....##
+.#...#.
+..#.#..
+...#...
+
+z=0
+...#...
+.......
+#......
+.......
+.....##
+.##.#..
+...#...
+
+z=1
+..#....
+...#...
+#......
+.....##
+.#...#.
+..#.#..
+...#...
+
+z=2
+.......
+.......
+..##...
+..###..
+.......
+.......
+.......
+
+After the full six-cycle boot process completes, 112 cubes are left in the active state.
+
+Starting with your given initial configuration, simulate six cycles. How many cubes are left in the
+active state after the sixth cycle?
+*/
+
+// We let the inactive state be the 3x3x3 space around active cubes.
+/// The cubes of the 3-dimensional pocket dimension that matter for the next cycle.
+#[derive(Debug, Clone)]
+pub struct State {
+    active: HashSet<Vec3>,
+    inactive: HashSet<Vec3>,
+}
+
+/// The cubes of the 4-dimensional pocket dimension that matter for the next cycle.
+#[derive(Debug, Clone)]
+pub struct StateW {
+    active: HashSet<Vec4>,
+    inactive: HashSet<Vec4>,
+}
+
+impl State {
+    /// The active cubes.
+    pub fn active(&self) -> &HashSet<Vec3> {
+        &self.active
+    }
+}
+
+impl StateW {
+    /// The active cubes.
+    pub fn active(&self) -> &HashSet<Vec4> {
+        &self.active
+    }
+}
+
+fn match_inactive(active: &HashSet<Vec3>) -> HashSet<Vec3> {
+    active
+        .iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|neighbor| !active.contains(neighbor))
+        .collect()
+}
+
+fn match_inactive_w(active: &HashSet<Vec4>) -> HashSet<Vec4> {
+    active
+        .iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|neighbor| !active.contains(neighbor))
+        .collect()
+}
+
+// The active cubes of the initial slice, as (x, y) coordinates.
+/// Parses the initial slice: `.` for an inactive cube, `#` for an active one. Returns the `(x, y)`
+/// positions of the active cubes.
+pub fn parse_input(input: &str) -> ParseResult<Vec<(i32, i32)>> {
+    let input = &*input::normalize(input);
+    // x will be line width, growing right
+    // y will be number of lines, growing down
+    let slice = Grid::parse_with(input, "`.` or `#`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    Ok(slice
+        .iter()
+        .filter(|(_, &active)| active)
+        .map(|((x, y), _)| (x as i32, y as i32))
+        .collect())
+}
+
+/// The 3-dimensional pocket dimension with the active cubes of `cells` in the slice `z = 0`.
+pub fn initial_state(cells: &[(i32, i32)]) -> State {
+    // z = 0, growing "up"
+    let active: HashSet<Vec3> = cells
+        .iter()
+        .map(|&(x, y)| Vec3::new(i64::from(x), i64::from(y), 0))
+        .collect();
+
+    let inactive = match_inactive(&active);
+    State { active, inactive }
+}
+
+/// The 4-dimensional pocket dimension with the active cubes of `cells` in the slice
+/// `z = 0, w = 0`.
+pub fn initial_state_w(cells: &[(i32, i32)]) -> StateW {
+    // z = 0, w = 0
+    let active: HashSet<Vec4> = cells
+        .iter()
+        .map(|&(x, y)| Vec4::new(i64::from(x), i64::from(y), 0, 0))
+        .collect();
+
+    let inactive = match_inactive_w(&active);
+    StateW { active, inactive }
+}
+
+/// Runs a single boot cycle in three dimensions.
+pub fn cycle(state: &State) -> State {
+    let mut active: HashSet<Vec3> = HashSet::new();
+
+    // evaluate active cubes
+    for cube in &state.active {
+        let num_neighbors = cube
+            .neighbours()
+            .filter(|neighbor| state.active.contains(neighbor))
+            .count();
+        if (num_neighbors == 2) || (num_neighbors == 3) {
+            active.insert(*cube);
+            trace!("{:?} stays active", cube);
+        } else {
+            trace!("{:?} now inactive", cube);
+        }
+    }
+
+    // evaluate inactive cubes
+    for cube in &state.inactive {
+        let num_neighbors = cube
+            .neighbours()
+            .filter(|neighbor| state.active.contains(neighbor))
+            .count();
+        if num_neighbors == 3 {
+            active.insert(*cube);
+            trace!("{:?} now active", cube);
+        }
+    }
+
+    let inactive = match_inactive(&active);
+    State { active, inactive }
+}
+
+/// Runs a single boot cycle in four dimensions.
+pub fn cycle_w(state: &StateW) -> StateW {
+    let mut active: HashSet<Vec4> = HashSet::new();
+
+    // evaluate active cubes
+    for cube in &state.active {
+        let num_neighbors = cube
+            .neighbours()
+            .filter(|neighbor| state.active.contains(neighbor))
+            .count();
+        if (num_neighbors == 2) || (num_neighbors == 3) {
+            active.insert(*cube);
+        }
+    }
+
+    // evaluate inactive cubes
+    for cube in &state.inactive {
+        let num_neighbors = cube
+            .neighbours()
+            .filter(|neighbor| state.active.contains(neighbor))
+            .count();
+        if num_neighbors == 3 {
+            active.insert(*cube);
+        }
+    }
+
+    let inactive = match_inactive_w(&active);
+    StateW { active, inactive }
+}
+
+/// Shows every `z` slice of the part of the pocket dimension that holds active cubes.
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Get extents
+        let min = |axis: fn(&Vec3) -> i64| self.active.iter().map(axis).min().unwrap_or(0);
+        let max = |axis: fn(&Vec3) -> i64| self.active.iter().map(axis).max().unwrap_or(-1);
+
+        for z in min(|c| c.z)..=max(|c| c.z) {
+            writeln!(f, "z={}", z)?;
+            for y in min(|c| c.y)..=max(|c| c.y) {
+                for x in min(|c| c.x)..=max(|c| c.x) {
+                    if self.active.contains(&Vec3::new(x, y, z)) {
+                        write!(f, "#")?;
+                    } else {
+                        write!(f, ".")?;
+                    }
+                }
+                writeln!(f)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Shows every `z, w` slice of the part of the pocket dimension that holds active cubes.
+impl fmt::Display for StateW {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let min = |axis: fn(&Vec4) -> i64| self.active.iter().map(axis).min().unwrap_or(0);
+        let max = |axis: fn(&Vec4) -> i64| self.active.iter().map(axis).max().unwrap_or(-1);
+
+        for w in min(|c| c.w)..=max(|c| c.w) {
+            for z in min(|c| c.z)..=max(|c| c.z) {
+                writeln!(f, "z={}, w={}", z, w)?;
+                for y in min(|c| c.y)..=max(|c| c.y) {
+                    for x in min(|c| c.x)..=max(|c| c.x) {
+                        if self.active.contains(&Vec4::new(x, y, z, w)) {
+                            write!(f, "#")?;
+                        } else {
+                            write!(f, ".")?;
+                        }
+                    }
+                    writeln!(f)?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Number of active cubes after six cycles in three dimensions.
+pub fn part_one(cells: &[(i32, i32)]) -> usize {
+    let mut state = initial_state(cells);
+    trace!("Before any cycles:\n{}", state);
+
+    for i in 1..=6 {
+        state = cycle(&state);
+        trace!("After {} cycles:\n{}", i, state);
+    }
+
+    state.active.len()
+}
+
+/*
+--- Part Two ---
+
+For some reason, your simulated results don't match what the experimental energy source engineers
+expected. Apparently, the pocket dimension actually has four spatial dimensions, not three.
+
+The pocket dimension contains an infinite 4-dimensional grid. At every integer 4-dimensional
+coordinate (x,y,z,w), there exists a single cube (really, a hypercube) which is still either active
+or inactive.
+
+Each cube only ever considers its neighbors: any of the 80 other cubes where any of their
+coordinates differ by at most 1. For example, given the cube at x=1,y=2,z=3,w=4, its neighbors
+include the cube at x=2,y=2,z=3,w=3, the cube at x=0,y=2,z=3,w=4, and so on.
+
+The initial state of the pocket dimension still consists of a small flat region of cubes.
+Furthermore, the same rules for cycle updating still apply: during each cycle, consider the number
+of active neighbors of each cube.
+
+For example, consider the same initial state as in the example above. Even though the pocket
+dimension is 4-dimensional, this initial state represents a small 2-dimensional slice of it.
+(In particular, this initial state defines a 3x3x1x1 region of the 4-dimensional space.)
+
+Simulating a few cycles from this initial state produces the following configurations, where the
+result of each cycle is shown layer-by-layer at each given z and w coordinate:
+
+Before any cycles:
+
+z=0, w=0
+.#.
+..#
+###
+
+
+After 1 cycle:
+
+z=-1, w=-1
+#..
+..#
+.#.
+
+z=0, w=-1
+#..
+..#
+.#.
+
+z=1, w=-1
+#..
+..#
+.#.
+
+z=-1, w=0
+#..
+..#
+.#.
+
+z=0, w=0
+#.#
+.##
+.#.
+
+z=1, w=0
+#..
+..#
+.#.
+
+z=-1, w=1
+#..
+..#
+.#.
+
+z=0, w=1
+#..
+..#
+.#.
+
+z=1, w=1
+#..
+..#
+.#.
+
+
+After 2 cycles:
+
+z=-2, w=-2
+.....
+.....
+..#..
+.....
+.....
+
+z=-1, w=-2
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=-2
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=1, w=-2
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=-2
+.....
+.....
+..#..
+.....
+.....
+
+z=-2, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=-1, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=1, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=-2, w=0
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=-1, w=0
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=0
+.....
+.....
+.....
+.....
+.....
+
+z=1, w=0
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=0
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=-2, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=-1, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=1, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=-2, w=2
+.....
+.....
+..#..
+.....
+.....
+
+z=-1, w=2
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=2
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=1, w=2
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=2
+.....
+.....
+..#..
+.....
+.....
+
+After the full six-cycle boot process completes, 848 cubes are left in the active state.
+
+Starting with your given initial configuration, simulate six cycles in a 4-dimensional space. How
+many cubes are left in the active state after the sixth cycle?
+*/
+
+/// Number of active cubes after six cycles in four dimensions.
+pub fn part_two(cells: &[(i32, i32)]) -> usize {
+    let mut state = initial_state_w(cells);
+
+    for _ in 1..=6 {
+        state = cycle_w(&state);
+    }
+
+    state.active.len()
+}
+
+/// The pocket dimension partway through the boot process, for following along.
+#[derive(Debug, Clone)]
+pub struct Boot {
+    /// Number of cycles run so far.
+    pub cycles: usize,
+    pub pocket: Pocket,
+}
+
+/// The pocket dimension of either part.
+#[derive(Debug, Clone)]
+pub enum Pocket {
+    Three(State),
+    Four(StateW),
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Conway Cubes";
+
+    type Input = Vec<(i32, i32)>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Simulation for Day17 {
+    type State = Boot;
+
+    const COUNTED: &'static str = "active cubes";
+
+    fn start(cells: &Self::Input, part: Part) -> Boot {
+        let pocket = match part {
+            Part::One => Pocket::Three(initial_state(cells)),
+            Part::Two => Pocket::Four(initial_state_w(cells)),
+        };
+        Boot { cycles: 0, pocket }
+    }
+
+    // The boot process is six cycles long.
+    fn step(_cells: &Self::Input, boot: &Boot, _part: Part) -> Option<Boot> {
+        if boot.cycles == 6 {
+            return None;
+        }
+        let pocket = match &boot.pocket {
+            Pocket::Three(state) => Pocket::Three(cycle(state)),
+            Pocket::Four(state) => Pocket::Four(cycle_w(state)),
+        };
+
+        Some(Boot {
+            cycles: boot.cycles + 1,
+            pocket,
+        })
+    }
+
+    fn count(boot: &Boot) -> usize {
+        match &boot.pocket {
+            Pocket::Three(state) => state.active.len(),
+            Pocket::Four(state) => state.active.len(),
+        }
+    }
+
+    fn render(boot: &Boot) -> String {
+        match &boot.pocket {
+            Pocket::Three(state) => state.to_string(),
+            Pocket::Four(state) => state.to_string(),
+        }
+    }
+}
+
+impl Generate for Day17 {
+    const SIZE: usize = 8;
+
+    // The size is the width and height of the initial slice.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut slice = String::new();
+        for _ in 0..size.max(1) {
+            for _ in 0..size.max(1) {
+                slice.push(if rng.chance(0.4) { '#' } else { '.' });
+            }
+            slice.push('\n');
+        }
+
+        slice
+    }
+}
+
+// Runs six cycles in `dimensions` dimensions, checking every cube within reach of an active one
+// against all cubes around it. Returns the number of active cubes.
+fn reference_cycles(cells: &[(i32, i32)], dimensions: usize) -> usize {
+    let mut offsets = vec![vec![]];
+    for _ in 0..dimensions {
+        offsets = offsets
+            .iter()
+            .flat_map(|o: &Vec<i32>| (-1..=1).map(move |d| [o.clone(), vec![d]].concat()))
+            .collect();
+    }
+    offsets.retain(|o| o.iter().any(|&d| d != 0));
+
+    let mut active: HashSet<Vec<i32>> = cells
+        .iter()
+        .map(|&(x, y)| [vec![x, y], vec![0; dimensions - 2]].concat())
+        .collect();
+    for _ in 0..6 {
+        let near = |cube: &Vec<i32>| -> Vec<Vec<i32>> {
+            offsets
+                .iter()
+                .map(|o| cube.iter().zip(o).map(|(c, d)| c + d).collect())
+                .collect()
+        };
+        let candidates: HashSet<Vec<i32>> = active.iter().flat_map(near).collect();
+        active = candidates
+            .into_iter()
+            .filter(|cube| {
+                let neighbours = near(cube).iter().filter(|n| active.contains(*n)).count();
+                neighbours == 3 || (neighbours == 2 && active.contains(cube))
+            })
+            .collect();
+    }
+
+    active.len()
+}
+
+impl Reference for Day17 {
+    const REFERENCE_SIZE: usize = 4;
+
+    fn reference_part_one(cells: &Self::Input) -> Option<Answer> {
+        Some(reference_cycles(cells, 3).into())
+    }
+
+    fn reference_part_two(cells: &Self::Input) -> Option<Answer> {
+        Some(reference_cycles(cells, 4).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::simulation;
+
+    const EXAMPLE: &str = "\
+.#.
+..#
+###
+";
+
+    #[test]
+    fn example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part_one(&input), Answer::Number(112));
+        assert_eq!(Day17::part_two(&input), Answer::Number(848));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Day17::parse(include_str!("../input")).unwrap();
+        assert_eq!(Day17::part_one(&input), Answer::Number(382));
+        assert_eq!(Day17::part_two(&input), Answer::Number(2552));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let text = Day17::generate(&mut Rng::new(seed), 8);
+            let input = Day17::parse(&text).unwrap();
+            assert_eq!(input.len(), text.matches('#').count());
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        common::reference::check::<Day17>();
+    }
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day17>(EXAMPLE);
+    }
+
+    #[test]
+    fn simulation() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        let one = simulation::run_to_end::<Day17>(&input, Part::One, 10);
+        let two = simulation::run_to_end::<Day17>(&input, Part::Two, 10);
+        assert_eq!((one, two), (Some(112), Some(848)));
+
+        let start = Day17::start(&input, Part::Two);
+        assert_eq!(Day17::render(&start), "z=0, w=0\n.#.\n..#\n###\n\n");
+    }
+}
@@ -0,0 +1,119 @@
+//! Solution to [Day 18: Operation Order](https://adventofcode.com/2020/day/18).
+
+/*
+--- Day 18: Operation Order ---
+
+As you look out the window and notice a heavily-forested continent slowly appear over the horizon,
+you are interrupted by the child sitting next to you. They're curious if you could help them with
+their math homework.
+
+Unfortunately, it seems like this "math" follows different rules than you remember.
+
+The homework (your puzzle input) consists of a series of expressions that consist of addition (+),
+multiplication (*), and parentheses ((...)). Just like normal math, parentheses indicate that the
+expression inside must be evaluated before it can be used by the surrounding expression. Addition
+still finds the sum of the numbers on both sides of the operator, and multiplication still finds the
+product.
+
+However, the rules of operator precedence have changed. Rather than evaluating multiplication before
+addition, the operators have the same precedence, and are evaluated left-to-right regardless of the
+order in which they appear.
+
+For example, the steps to evaluate the expression 1 + 2 * 3 + 4 * 5 + 6 are as follows:
+
+1 + 2 * 3 + 4 * 5 + 6
+  3   * 3 + 4 * 5 + 6
+      9   + 4 * 5 + 6
+         13   * 5 + 6
+             65   + 6
+                 71
+
+Parentheses can override this order; for example, here is what happens if parentheses are added to
+form 1 + (2 * 3) + (4 * (5 + 6)):
+
+1 + (2 * 3) + (4 * (5 + 6))
+1 +    6    + (4 * (5 + 6))
+     7      + (4 * (5 + 6))
+     7      + (4 *   11   )
+     7      +     44
+            51
+
+Here are a few more examples:
+
+    2 * 3 + (4 * 5) becomes 26.
+    5 + (8 * 3 + 9 + 3 * 4 * 3) becomes 437.
+    5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4)) becomes 12240.
+    ((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2 becomes 13632.
+
+Before you can help with the homework, you need to understand it yourself. Evaluate the expression
+on each line of the homework; what is the sum of the resulting values?
+*/
+
+use common::input;
+use common::{Answer, Generate, ParseResult, Rng, Solution};
+
+/// Parses the homework, one expression per line.
+pub fn parse_input(input: &str) -> ParseResult<Vec<String>> {
+    let input = &*input::normalize(input);
+    Ok(input.lines().map(str::to_owned).collect())
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Operation Order";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    // Not solved yet.
+    fn part_one(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+// A term: a single digit, or an expression in parentheses while `depth` allows it.
+fn term(rng: &mut Rng, depth: u32) -> String {
+    if depth > 0 && rng.chance(0.25) {
+        format!("({})", expression(rng, depth - 1))
+    } else {
+        rng.range(1..=9).to_string()
+    }
+}
+
+fn expression(rng: &mut Rng, depth: u32) -> String {
+    let mut expression = term(rng, depth);
+    for _ in 0..rng.range(1..=5) {
+        expression.push_str(if rng.chance(0.5) { " + " } else { " * " });
+        expression.push_str(&term(rng, depth));
+    }
+
+    expression
+}
+
+impl Generate for Day18 {
+    const SIZE: usize = 380;
+
+    // Parentheses are nested at most two deep, like in the real input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| expression(rng, 2) + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn untidy_input() {
+        input::check_normalized::<Day18>("1 + 2 * 3 + 4 * 5 + 6\n2 * 3 + (4 * 5)\n");
+    }
+}
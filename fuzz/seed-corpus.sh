@@ -0,0 +1,14 @@
+#!/bin/sh
+# Seeds each fuzz target's corpus with the day's puzzle input and the examples from the puzzle
+# text. Run from anywhere; existing corpus entries are kept.
+set -e
+cd "$(dirname "$0")"
+for seeds in seeds/day*; do
+    day=$(basename "$seeds")
+    corpus=corpus/parse_$day
+    mkdir -p "$corpus"
+    cp "$seeds"/*.txt "$corpus"/
+    if [ -f "../$day/input" ]; then
+        cp "../$day/input" "$corpus/input.txt"
+    fi
+done